@@ -0,0 +1,101 @@
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator};
+use anyhow::{bail, Result};
+use serde::Serialize;
+use stablecoin::{
+    calculate_health_factor, Config, Position, Wad, CONFIG_SEED, MINT_SEED, POSITION_SEED,
+    STAKE_SEED, VAULT_SEED,
+};
+
+pub fn config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &stablecoin::ID).0
+}
+
+pub fn mint_pda() -> Pubkey {
+    Pubkey::find_program_address(&[MINT_SEED], &stablecoin::ID).0
+}
+
+pub fn position_pda(depositor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POSITION_SEED, depositor.as_ref()], &stablecoin::ID).0
+}
+
+pub fn vault_pda(position: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, position.as_ref()], &stablecoin::ID).0
+}
+
+pub fn stake_pda(position: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[STAKE_SEED, position.as_ref()], &stablecoin::ID).0
+}
+
+/// Program account decoded by its discriminator.
+pub enum ProgramAccount {
+    Config(Box<Config>),
+    Position(Position),
+}
+
+impl ProgramAccount {
+    /// Decodes `Config` or `Position` data, returning `None` for any other account type.
+    pub fn decode(data: &[u8]) -> Result<Option<ProgramAccount>> {
+        if data.starts_with(Config::DISCRIMINATOR) {
+            return Ok(Some(ProgramAccount::Config(Box::new(decode_config(data)?))));
+        }
+
+        if data.starts_with(Position::DISCRIMINATOR) {
+            return Ok(Some(ProgramAccount::Position(decode_position(data)?)));
+        }
+
+        Ok(None)
+    }
+}
+
+pub fn decode_config(data: &[u8]) -> Result<Config> {
+    Ok(Config::try_deserialize(&mut &data[..])?)
+}
+
+pub fn decode_position(data: &[u8]) -> Result<Position> {
+    Ok(Position::try_deserialize(&mut &data[..])?)
+}
+
+/// Reads the supply of an SPL Token or Token-2022 mint.
+pub fn decode_mint_supply(data: &[u8]) -> Result<u64> {
+    // supply follows the 36-byte mint authority option in both token programs
+    let Some(supply) = data.get(36..44) else {
+        bail!("mint account data too short");
+    };
+
+    Ok(u64::from_le_bytes(supply.try_into()?))
+}
+
+/// Position together with its collateral, the lamports held in its vault plus any staked.
+#[derive(Clone, Debug, Serialize)]
+pub struct PositionSnapshot {
+    pub address: String,
+    pub depositor: String,
+    pub collateral: u64,
+    pub amount_minted: u64,
+    pub auction_ends_at: i64,
+}
+
+impl PositionSnapshot {
+    pub fn new(address: &Pubkey, position: &Position, vault_lamports: u64) -> Self {
+        Self {
+            address: address.to_string(),
+            depositor: position.depositor.to_string(),
+            collateral: vault_lamports + position.staked_collateral,
+            amount_minted: position.amount_minted,
+            auction_ends_at: position.auction_ends_at,
+        }
+    }
+
+    pub fn health_factor(&self, price: Wad) -> Result<Wad> {
+        Ok(calculate_health_factor(
+            self.collateral,
+            self.amount_minted,
+            price,
+        )?)
+    }
+}
+
+/// Converts a health factor to basis points, saturating for positions without debt.
+pub fn health_factor_bps(health_factor: Wad) -> u64 {
+    u64::try_from(health_factor.0 / Wad::from_bps(1).0).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,111 @@
+use std::{fs, path::Path, str::FromStr};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+/// Account in the `solana account --output json` format, as used by `tests/fixtures`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountFixture {
+    pub pubkey: String,
+    pub account: FixtureAccount,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixtureAccount {
+    pub lamports: u64,
+    /// Base64 encoded data and its encoding.
+    pub data: (String, String),
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub space: Option<u64>,
+}
+
+impl AccountFixture {
+    pub fn new(pubkey: &Pubkey, lamports: u64, data: &[u8], owner: &Pubkey) -> Self {
+        Self {
+            pubkey: pubkey.to_string(),
+            account: FixtureAccount {
+                lamports,
+                data: (STANDARD.encode(data), "base64".to_string()),
+                owner: owner.to_string(),
+                executable: false,
+                rent_epoch: u64::MAX,
+                space: Some(data.len() as u64),
+            },
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).with_context(|| format!("reading {path:?}"))?;
+
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn pubkey(&self) -> Result<Pubkey> {
+        Ok(Pubkey::from_str(&self.pubkey)?)
+    }
+
+    pub fn owner(&self) -> Result<Pubkey> {
+        Ok(Pubkey::from_str(&self.account.owner)?)
+    }
+
+    pub fn data(&self) -> Result<Vec<u8>> {
+        let (data, encoding) = &self.account.data;
+
+        if encoding != "base64" {
+            bail!("unsupported account data encoding {encoding}");
+        }
+
+        Ok(STANDARD.decode(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{AnchorDeserialize, Discriminator, Space};
+    use stablecoin::{Config, ConfigV0, Position, PositionV0};
+
+    use super::*;
+
+    fn fixture_data(name: &str) -> Vec<u8> {
+        AccountFixture::load(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../tests/fixtures")
+                .join(name),
+        )
+        .unwrap()
+        .data()
+        .unwrap()
+    }
+
+    #[test]
+    fn legacy_fixtures_use_initial_release_layouts() {
+        let config_data = fixture_data("config_v0.json");
+        let position_data = fixture_data("position_v0.json");
+
+        // account sizes written by the initial release
+        assert_eq!(Config::DISCRIMINATOR.len() + ConfigV0::INIT_SPACE, 48);
+        assert_eq!(Position::DISCRIMINATOR.len() + PositionV0::INIT_SPACE, 50);
+        assert_eq!(config_data.len(), 48);
+        assert_eq!(position_data.len(), 50);
+
+        let config = Config::from(
+            ConfigV0::deserialize(&mut &config_data[Config::DISCRIMINATOR.len()..]).unwrap(),
+        );
+        let position = Position::from(
+            PositionV0::deserialize(&mut &position_data[Position::DISCRIMINATOR.len()..]).unwrap(),
+        );
+
+        assert_eq!(config.compliance_authority, config.authority);
+        assert_eq!(config.min_loan_to_value_bps, 15000);
+        assert_eq!(config.auction_duration, 0);
+        assert_eq!(position.amount_minted, 250_000_000);
+        assert_eq!(position.auction_ends_at, 0);
+    }
+}
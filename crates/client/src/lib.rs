@@ -0,0 +1,21 @@
+//! Off-chain helpers shared by the stablecoin services: account decoding, PDA derivation, RPC
+//! fetching, oracle price parsing and `solana account --output json` fixtures.
+
+pub mod accounts;
+pub use accounts::*;
+
+pub mod fixture;
+pub use fixture::*;
+
+pub mod oracle;
+pub use oracle::*;
+
+pub mod rpc;
+pub use rpc::*;
+
+pub mod state;
+pub use state::*;
+
+pub use stablecoin::{
+    calculate_health_factor, Config, Position, Wad, MAX_BASIS_POINTS, MINT_DECIMALS, WAD,
+};
@@ -0,0 +1,111 @@
+use anchor_lang::{
+    prelude::{pubkey::PUBKEY_BYTES, Rent},
+    Discriminator,
+};
+use anyhow::{anyhow, bail, Result};
+use stablecoin::{get_price_from_quote, Wad, SOL_USD_FEED_ID};
+use switchboard_on_demand::{
+    default_queue, on_demand::oracle_quote::test_utils::QuoteBuilder, QuoteVerifier,
+    SwitchboardQuote, QUOTE_PROGRAM_ID,
+};
+
+use crate::AccountFixture;
+
+/// Reads the SOL/USD price from Switchboard quote account data.
+///
+/// Signatures and staleness are not verified, matching the program's `no-staleness-check` build.
+/// Only use the result for reporting, never to decide whether a transaction is safe to send.
+pub fn price_from_quote_data(data: &[u8]) -> Result<Wad> {
+    // discriminator + queue pubkey
+    let offset = SwitchboardQuote::DISCRIMINATOR.len() + PUBKEY_BYTES;
+
+    if data.len() <= offset {
+        bail!("oracle quote account data too short");
+    }
+
+    let verifier = QuoteVerifier::new();
+    let quote = verifier
+        .parse_unverified_delimited(&data[offset..])
+        .map_err(|err| anyhow!("invalid oracle quote: {err:?}"))?;
+
+    Ok(get_price_from_quote(quote)?)
+}
+
+/// Builds an unsigned SOL/USD quote account at its canonical address with `price` recorded at
+/// `slot`.
+///
+/// Only the `no-staleness-check` build accepts it, so it can stand in for a devnet quote in
+/// LiteSVM without network access.
+pub fn stub_quote_fixture(price: f64, slot: u64) -> Result<AccountFixture> {
+    let queue = default_queue();
+    let quote = QuoteBuilder::new(queue)
+        .add_feed(&SOL_USD_FEED_ID, price)
+        .slot(slot)
+        .build()
+        .map_err(|err| anyhow!("building oracle quote: {err}"))?;
+    let data = quote
+        .to_account_data()
+        .map_err(|err| anyhow!("serializing oracle quote: {err}"))?;
+
+    Ok(AccountFixture::new(
+        &quote.canonical_key(&queue, &QUOTE_PROGRAM_ID),
+        Rent::default().minimum_balance(data.len()),
+        &data,
+        &QUOTE_PROGRAM_ID,
+    ))
+}
+
+/// Parses a decimal USD price such as `152.37` into a `Wad`.
+pub fn parse_price(price: &str) -> Result<Wad> {
+    let (integer, fraction) = price.split_once('.').unwrap_or((price, ""));
+
+    if fraction.len() > 18 || (integer.is_empty() && fraction.is_empty()) {
+        bail!("invalid price {price}");
+    }
+
+    let integer: u128 = if integer.is_empty() {
+        0
+    } else {
+        integer.parse()?
+    };
+    let fraction: u128 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<18}").parse()?
+    };
+
+    integer
+        .checked_mul(stablecoin::WAD)
+        .and_then(|integer| integer.checked_add(fraction))
+        .map(Wad)
+        .ok_or_else(|| anyhow!("price {price} out of range"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_decimal_prices() {
+        assert_eq!(parse_price("150").unwrap(), Wad(150 * stablecoin::WAD));
+        assert_eq!(parse_price("0.000000000000000001").unwrap(), Wad(1));
+        assert_eq!(
+            parse_price("152.37").unwrap(),
+            Wad(152_370_000_000_000_000_000)
+        );
+        assert!(parse_price(".").is_err());
+        assert!(parse_price("1.0000000000000000001").is_err());
+        assert!(parse_price("-1").is_err());
+    }
+
+    #[test]
+    fn stub_quote_round_trips_price() {
+        let fixture = stub_quote_fixture(150.25, 1).unwrap();
+
+        assert_eq!(fixture.owner().unwrap(), QUOTE_PROGRAM_ID);
+        assert_eq!(
+            price_from_quote_data(&fixture.data().unwrap()).unwrap(),
+            parse_price("150.25").unwrap()
+        );
+    }
+}
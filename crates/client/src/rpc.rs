@@ -0,0 +1,109 @@
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use anyhow::{Context, Result};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use stablecoin::{Config, Position, Wad};
+
+use crate::{
+    config_pda, decode_config, decode_mint_supply, decode_position, mint_pda,
+    price_from_quote_data, vault_pda, PositionSnapshot,
+};
+
+/// Maximum accounts per `getMultipleAccounts` request.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+pub async fn fetch_config(rpc: &RpcClient) -> Result<Config> {
+    let data = rpc
+        .get_account_data(&config_pda())
+        .await
+        .context("fetching config")?;
+
+    decode_config(&data)
+}
+
+pub async fn fetch_mint_supply(rpc: &RpcClient) -> Result<u64> {
+    let data = rpc
+        .get_account_data(&mint_pda())
+        .await
+        .context("fetching mint")?;
+
+    decode_mint_supply(&data)
+}
+
+pub async fn fetch_price(rpc: &RpcClient, oracle_quote: &Pubkey) -> Result<Wad> {
+    let data = rpc
+        .get_account_data(oracle_quote)
+        .await
+        .context("fetching oracle quote")?;
+
+    price_from_quote_data(&data)
+}
+
+/// Fetches every `Position` account.
+pub async fn fetch_positions(rpc: &RpcClient) -> Result<Vec<(Pubkey, Position)>> {
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &stablecoin::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                    0,
+                    Position::DISCRIMINATOR,
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .context("fetching positions")?;
+
+    accounts
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode_position(&account.data)?)))
+        .collect()
+}
+
+/// Fetches the lamports held by each vault, 0 for vaults that do not exist.
+pub async fn fetch_vault_balances(rpc: &RpcClient, positions: &[Pubkey]) -> Result<Vec<u64>> {
+    let vaults = positions.iter().map(vault_pda).collect::<Vec<_>>();
+    let mut balances = Vec::with_capacity(vaults.len());
+
+    for chunk in vaults.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let accounts = rpc
+            .get_multiple_accounts(chunk)
+            .await
+            .context("fetching vaults")?;
+
+        balances.extend(
+            accounts
+                .into_iter()
+                .map(|account| account.map_or(0, |account| account.lamports)),
+        );
+    }
+
+    Ok(balances)
+}
+
+/// Fetches every position with its vault balance.
+pub async fn fetch_position_snapshots(rpc: &RpcClient) -> Result<Vec<PositionSnapshot>> {
+    let positions = fetch_positions(rpc).await?;
+    let addresses = positions
+        .iter()
+        .map(|(address, _)| *address)
+        .collect::<Vec<_>>();
+    let balances = fetch_vault_balances(rpc, &addresses).await?;
+
+    Ok(positions
+        .iter()
+        .zip(balances)
+        .map(|((address, position), collateral)| {
+            PositionSnapshot::new(address, position, collateral)
+        })
+        .collect())
+}
@@ -0,0 +1,115 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Result};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use stablecoin::{Config, Wad};
+
+use crate::{
+    decode_mint_supply, fetch_config, fetch_mint_supply, fetch_position_snapshots, fetch_price,
+    mint_pda, price_from_quote_data, vault_pda, AccountFixture, PositionSnapshot, ProgramAccount,
+};
+
+/// Protocol accounts needed to assess risk at a point in time.
+pub struct ProtocolState {
+    pub config: Config,
+    pub positions: Vec<PositionSnapshot>,
+    pub supply: u64,
+    /// SOL/USD price, `None` if no oracle quote was read.
+    pub price: Option<Wad>,
+}
+
+impl ProtocolState {
+    pub async fn fetch(rpc: &RpcClient, oracle_quote: Option<&Pubkey>) -> Result<Self> {
+        let price = match oracle_quote {
+            Some(oracle_quote) => Some(fetch_price(rpc, oracle_quote).await?),
+            None => None,
+        };
+
+        Ok(Self {
+            config: fetch_config(rpc).await?,
+            positions: fetch_position_snapshots(rpc).await?,
+            supply: fetch_mint_supply(rpc).await?,
+            price,
+        })
+    }
+
+    /// Builds state from account fixtures, treating missing vaults as empty.
+    pub fn from_fixtures(
+        fixtures: &[AccountFixture],
+        oracle_quote: Option<&Pubkey>,
+    ) -> Result<Self> {
+        let mut config = None;
+        let mut positions = vec![];
+        let mut supply = 0;
+        let mut price = None;
+        let mut lamports = HashMap::new();
+
+        for fixture in fixtures {
+            let pubkey = fixture.pubkey()?;
+            let data = fixture.data()?;
+
+            lamports.insert(pubkey, fixture.account.lamports);
+
+            if Some(&pubkey) == oracle_quote {
+                price = Some(price_from_quote_data(&data)?);
+            } else if pubkey == mint_pda() {
+                supply = decode_mint_supply(&data)?;
+            } else if fixture.owner()? == stablecoin::ID {
+                match ProgramAccount::decode(&data)? {
+                    Some(ProgramAccount::Config(account)) => config = Some(*account),
+                    Some(ProgramAccount::Position(position)) => positions.push((pubkey, position)),
+                    None => {}
+                }
+            }
+        }
+
+        Ok(Self {
+            config: config.ok_or_else(|| anyhow!("config account missing from fixtures"))?,
+            positions: positions
+                .iter()
+                .map(|(address, position)| {
+                    let collateral = lamports.get(&vault_pda(address)).copied().unwrap_or(0);
+
+                    PositionSnapshot::new(address, position, collateral)
+                })
+                .collect(),
+            supply,
+            price,
+        })
+    }
+
+    /// Loads every `.json` account fixture in `dir`.
+    pub fn load_fixtures(dir: impl AsRef<Path>, oracle_quote: Option<&Pubkey>) -> Result<Self> {
+        let mut fixtures = vec![];
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                fixtures.push(AccountFixture::load(path)?);
+            }
+        }
+
+        Self::from_fixtures(&fixtures, oracle_quote)
+    }
+
+    /// Sum of lamports held by position vaults.
+    pub fn total_collateral(&self) -> u64 {
+        self.positions
+            .iter()
+            .map(|position| position.collateral)
+            .sum()
+    }
+
+    /// Sum of stablecoin minted against positions.
+    pub fn total_debt(&self) -> u64 {
+        self.positions
+            .iter()
+            .map(|position| position.amount_minted)
+            .sum()
+    }
+}
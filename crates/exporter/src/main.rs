@@ -0,0 +1,136 @@
+//! Periodically reads stablecoin positions, mint supply and the SOL/USD price and exposes risk
+//! gauges for Prometheus.
+
+mod metrics;
+
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Result};
+use axum::{extract::State, http::StatusCode, routing::get, Router};
+use clap::Parser;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+use stablecoin::Wad;
+use stablecoin_client::{parse_price, ProtocolState};
+use tokio::net::TcpListener;
+use tracing::{error, info};
+use tracing_subscriber::EnvFilter;
+
+use crate::metrics::Metrics;
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Address the metrics endpoint listens on.
+    #[arg(long, default_value = "127.0.0.1:9100")]
+    listen: SocketAddr,
+    #[arg(long, env = "RPC_URL", default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    /// Reads accounts from a directory of `solana account --output json` dumps instead of RPC.
+    #[arg(long)]
+    fixtures: Option<PathBuf>,
+    /// Switchboard SOL/USD quote account to read prices from.
+    #[arg(long)]
+    oracle_quote: Option<Pubkey>,
+    /// Fixed SOL/USD price, e.g. `150.25`, overriding the oracle quote.
+    #[arg(long, value_parser = parse_price)]
+    price: Option<Wad>,
+    /// Seconds between updates.
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+    /// Number of at-risk positions exported individually.
+    #[arg(long, default_value_t = 10)]
+    at_risk_count: usize,
+}
+
+struct Exporter {
+    rpc: RpcClient,
+    fixtures: Option<PathBuf>,
+    oracle_quote: Option<Pubkey>,
+    price: Option<Wad>,
+    metrics: Metrics,
+}
+
+impl Exporter {
+    async fn update(&self) -> Result<()> {
+        let oracle_quote = self.oracle_quote.as_ref().filter(|_| self.price.is_none());
+        let state = match &self.fixtures {
+            Some(fixtures) => ProtocolState::load_fixtures(fixtures, oracle_quote)?,
+            None => ProtocolState::fetch(&self.rpc, oracle_quote).await?,
+        };
+        let price = self
+            .price
+            .or(state.price)
+            .ok_or_else(|| anyhow!("either --price or --oracle-quote is required"))?;
+
+        self.metrics.update(
+            &state,
+            price,
+            SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs_f64(),
+        )
+    }
+}
+
+async fn metrics(State(exporter): State<Arc<Exporter>>) -> Result<String, StatusCode> {
+    exporter
+        .metrics
+        .encode()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .init();
+
+    let Args {
+        listen,
+        rpc_url,
+        fixtures,
+        oracle_quote,
+        price,
+        interval,
+        at_risk_count,
+    } = Args::parse();
+    let exporter = Arc::new(Exporter {
+        rpc: RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed()),
+        fixtures,
+        oracle_quote,
+        price,
+        metrics: Metrics::new(at_risk_count)?,
+    });
+
+    tokio::spawn({
+        let exporter = exporter.clone();
+
+        async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval));
+
+            loop {
+                interval.tick().await;
+
+                // gauges keep their last values, `last_update_timestamp_seconds` shows staleness
+                if let Err(err) = exporter.update().await {
+                    error!("failed to update metrics: {err:#}");
+                }
+            }
+        }
+    });
+
+    let listener = TcpListener::bind(listen).await?;
+    let app = Router::new()
+        .route("/metrics", get(metrics))
+        .with_state(exporter);
+
+    info!(%listen, "serving metrics");
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use std::cmp::Reverse;
+
+use anyhow::Result;
+use prometheus::{Encoder, Gauge, GaugeVec, IntGauge, Opts, Registry, TextEncoder};
+use stablecoin::{Wad, WAD};
+use stablecoin_client::{calculate_health_factor, PositionSnapshot, ProtocolState};
+
+pub struct Metrics {
+    registry: Registry,
+    total_collateral: IntGauge,
+    total_debt: IntGauge,
+    total_supply: IntGauge,
+    tracked_total_collateral: IntGauge,
+    tracked_total_debt: IntGauge,
+    price: Gauge,
+    system_collateral_ratio: Gauge,
+    recovery_mode: IntGauge,
+    positions: IntGauge,
+    positions_liquidatable: IntGauge,
+    at_risk_debt: GaugeVec,
+    at_risk_health_factor: GaugeVec,
+    last_update: Gauge,
+    /// Number of at-risk positions exported individually.
+    at_risk_count: usize,
+}
+
+fn int_gauge(registry: &Registry, name: &str, help: &str) -> Result<IntGauge> {
+    let gauge = IntGauge::new(name, help)?;
+
+    registry.register(Box::new(gauge.clone()))?;
+
+    Ok(gauge)
+}
+
+fn gauge(registry: &Registry, name: &str, help: &str) -> Result<Gauge> {
+    let gauge = Gauge::new(name, help)?;
+
+    registry.register(Box::new(gauge.clone()))?;
+
+    Ok(gauge)
+}
+
+fn gauge_vec(registry: &Registry, name: &str, help: &str) -> Result<GaugeVec> {
+    let gauge = GaugeVec::new(Opts::new(name, help), &["position", "depositor"])?;
+
+    registry.register(Box::new(gauge.clone()))?;
+
+    Ok(gauge)
+}
+
+fn wad_to_f64(value: Wad) -> f64 {
+    if value == Wad::MAX {
+        f64::INFINITY
+    } else {
+        value.0 as f64 / WAD as f64
+    }
+}
+
+/// Positions with debt whose health factor at `price` is below `min_loan_to_value_bps`, largest
+/// debt first.
+pub fn at_risk_positions(
+    positions: &[PositionSnapshot],
+    price: Wad,
+    min_loan_to_value_bps: u16,
+) -> Result<Vec<(&PositionSnapshot, Wad)>> {
+    let min_ltv = Wad::from_bps(min_loan_to_value_bps);
+    let mut at_risk = vec![];
+
+    for position in positions {
+        let health_factor = position.health_factor(price)?;
+
+        if position.amount_minted > 0 && health_factor < min_ltv {
+            at_risk.push((position, health_factor));
+        }
+    }
+
+    at_risk.sort_by_key(|(position, _)| Reverse(position.amount_minted));
+
+    Ok(at_risk)
+}
+
+impl Metrics {
+    pub fn new(at_risk_count: usize) -> Result<Self> {
+        let registry = Registry::new_custom(Some("stablecoin".to_string()), None)?;
+
+        Ok(Self {
+            total_collateral: int_gauge(
+                &registry,
+                "total_collateral_lamports",
+                "Lamports held in all position vaults",
+            )?,
+            total_debt: int_gauge(
+                &registry,
+                "total_debt",
+                "Stablecoin base units minted against all positions",
+            )?,
+            total_supply: int_gauge(
+                &registry,
+                "total_supply",
+                "Stablecoin mint supply in base units",
+            )?,
+            tracked_total_collateral: int_gauge(
+                &registry,
+                "config_total_collateral_lamports",
+                "Collateral total tracked on the config account",
+            )?,
+            tracked_total_debt: int_gauge(
+                &registry,
+                "config_total_debt",
+                "Debt total tracked on the config account",
+            )?,
+            price: gauge(&registry, "sol_usd_price", "SOL/USD oracle price")?,
+            system_collateral_ratio: gauge(
+                &registry,
+                "system_collateral_ratio",
+                "Aggregate collateral value over aggregate debt",
+            )?,
+            recovery_mode: int_gauge(
+                &registry,
+                "recovery_mode",
+                "1 if the system collateral ratio is below the critical ratio",
+            )?,
+            positions: int_gauge(&registry, "positions", "Number of positions")?,
+            positions_liquidatable: int_gauge(
+                &registry,
+                "positions_liquidatable",
+                "Number of positions below the effective liquidation threshold",
+            )?,
+            at_risk_debt: gauge_vec(
+                &registry,
+                "at_risk_position_debt",
+                "Debt of the largest positions below the minimum loan-to-value",
+            )?,
+            at_risk_health_factor: gauge_vec(
+                &registry,
+                "at_risk_position_health_factor",
+                "Health factor of the largest positions below the minimum loan-to-value",
+            )?,
+            last_update: gauge(
+                &registry,
+                "last_update_timestamp_seconds",
+                "Unix timestamp of the last successful update",
+            )?,
+            registry,
+            at_risk_count,
+        })
+    }
+
+    pub fn update(&self, state: &ProtocolState, price: Wad, now: f64) -> Result<()> {
+        let ProtocolState {
+            config,
+            positions,
+            supply,
+            ..
+        } = state;
+        let total_collateral = state.total_collateral();
+        let total_debt = state.total_debt();
+        let recovery_mode = config.is_recovery_mode(price)?;
+        let threshold = Wad::from_bps(config.effective_liquidation_threshold_bps(recovery_mode));
+
+        let mut liquidatable = 0;
+
+        for position in positions {
+            if position.health_factor(price)? < threshold {
+                liquidatable += 1;
+            }
+        }
+
+        self.total_collateral.set(total_collateral as i64);
+        self.total_debt.set(total_debt as i64);
+        self.total_supply.set(*supply as i64);
+        self.tracked_total_collateral
+            .set(config.total_collateral as i64);
+        self.tracked_total_debt.set(config.total_debt as i64);
+        self.price.set(wad_to_f64(price));
+        self.system_collateral_ratio
+            .set(wad_to_f64(calculate_health_factor(
+                total_collateral,
+                total_debt,
+                price,
+            )?));
+        self.recovery_mode.set(recovery_mode.into());
+        self.positions.set(positions.len() as i64);
+        self.positions_liquidatable.set(liquidatable);
+
+        // drop positions that are no longer among the largest at risk
+        self.at_risk_debt.reset();
+        self.at_risk_health_factor.reset();
+
+        for (position, health_factor) in at_risk_positions(
+            positions,
+            price,
+            config.effective_min_loan_to_value_bps(recovery_mode),
+        )?
+        .into_iter()
+        .take(self.at_risk_count)
+        {
+            let labels = [position.address.as_str(), position.depositor.as_str()];
+
+            self.at_risk_debt
+                .with_label_values(&labels)
+                .set(position.amount_minted as f64);
+            self.at_risk_health_factor
+                .with_label_values(&labels)
+                .set(wad_to_f64(health_factor));
+        }
+
+        self.last_update.set(now);
+
+        Ok(())
+    }
+
+    pub fn encode(&self) -> Result<String> {
+        let mut buffer = vec![];
+
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{AnchorDeserialize, Space};
+    use stablecoin::Config;
+
+    use super::*;
+
+    fn position(address: &str, collateral: u64, amount_minted: u64) -> PositionSnapshot {
+        PositionSnapshot {
+            address: address.to_string(),
+            depositor: address.to_string(),
+            collateral,
+            amount_minted,
+            auction_ends_at: 0,
+        }
+    }
+
+    #[test]
+    fn orders_at_risk_positions_by_debt() {
+        let price = Wad(100 * WAD);
+        let positions = [
+            // 125%
+            position("a", 1_000_000_000, 80_000_000),
+            // 100%
+            position("b", 2_000_000_000, 200_000_000),
+            // 200%
+            position("c", 1_000_000_000, 50_000_000),
+            // no debt
+            position("d", 1_000_000_000, 0),
+        ];
+
+        let at_risk = at_risk_positions(&positions, price, 15000)
+            .unwrap()
+            .into_iter()
+            .map(|(position, _)| position.address.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(at_risk, vec!["b", "a"]);
+    }
+
+    #[test]
+    fn exports_protocol_state() {
+        let mut config = Config::deserialize(&mut &[0; Config::INIT_SPACE][..]).unwrap();
+
+        config.min_loan_to_value_bps = 15000;
+        config.liquidation_threshold_bps = 12500;
+
+        let state = ProtocolState {
+            config,
+            positions: vec![
+                position("a", 1_000_000_000, 90_000_000),
+                position("b", 1_000_000_000, 50_000_000),
+            ],
+            supply: 140_000_000,
+            price: None,
+        };
+        let metrics = Metrics::new(1).unwrap();
+
+        metrics.update(&state, Wad(100 * WAD), 1.0).unwrap();
+
+        let text = metrics.encode().unwrap();
+
+        for line in [
+            "stablecoin_total_collateral_lamports 2000000000",
+            "stablecoin_total_debt 140000000",
+            "stablecoin_total_supply 140000000",
+            "stablecoin_positions_liquidatable 1",
+            "stablecoin_at_risk_position_debt{depositor=\"a\",position=\"a\"} 90000000",
+        ] {
+            assert!(text.contains(line), "missing {line} in\n{text}");
+        }
+    }
+}
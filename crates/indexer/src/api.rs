@@ -0,0 +1,334 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use stablecoin::Wad;
+use stablecoin_client::{calculate_health_factor, health_factor_bps};
+
+use crate::{
+    db::{EventRow, PositionHistoryRow, PositionRow, SupplyRow},
+    indexer::Indexer,
+};
+
+/// Lower bounds of health factor buckets, in basis points.
+pub const HEALTH_FACTOR_BUCKETS_BPS: [u64; 7] = [0, 10000, 11000, 12500, 15000, 20000, 30000];
+
+pub fn router(indexer: Arc<Indexer>) -> Router {
+    Router::new()
+        .route("/positions", get(positions))
+        .route("/positions/{address}", get(position))
+        .route("/positions/{address}/history", get(position_history))
+        .route("/protocol", get(protocol))
+        .route("/protocol/supply", get(supply_history))
+        .route("/health-factors", get(health_factors))
+        .route("/events", get(events))
+        .with_state(indexer)
+}
+
+pub struct ApiError(StatusCode, String);
+
+impl From<anyhow::Error> for ApiError {
+    fn from(err: anyhow::Error) -> Self {
+        Self(StatusCode::INTERNAL_SERVER_ERROR, format!("{err:#}"))
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(serde_json::json!({ "error": self.1 }))).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+#[derive(Serialize)]
+pub struct PositionResponse {
+    #[serde(flatten)]
+    pub position: PositionRow,
+    /// Health factor in basis points, `None` without a price or for positions without debt.
+    pub health_factor_bps: Option<u64>,
+}
+
+fn with_health_factor(position: PositionRow, price: Option<Wad>) -> PositionResponse {
+    let health_factor_bps = price
+        .filter(|_| position.snapshot.amount_minted > 0)
+        .and_then(|price| position.snapshot.health_factor(price).ok())
+        .map(health_factor_bps);
+
+    PositionResponse {
+        position,
+        health_factor_bps,
+    }
+}
+
+async fn positions(State(indexer): State<Arc<Indexer>>) -> ApiResult<Vec<PositionResponse>> {
+    let price = indexer.db.latest_price()?;
+
+    Ok(Json(
+        indexer
+            .db
+            .positions()?
+            .into_iter()
+            .map(|position| with_health_factor(position, price))
+            .collect(),
+    ))
+}
+
+async fn position(
+    State(indexer): State<Arc<Indexer>>,
+    Path(address): Path<String>,
+) -> ApiResult<PositionResponse> {
+    let price = indexer.db.latest_price()?;
+    let position = indexer.db.position(&address)?.ok_or_else(|| {
+        ApiError(
+            StatusCode::NOT_FOUND,
+            format!("position {address} not found"),
+        )
+    })?;
+
+    Ok(Json(with_health_factor(position, price)))
+}
+
+async fn position_history(
+    State(indexer): State<Arc<Indexer>>,
+    Path(address): Path<String>,
+) -> ApiResult<Vec<PositionHistoryRow>> {
+    Ok(Json(indexer.db.position_history(&address)?))
+}
+
+#[derive(Serialize)]
+pub struct ProtocolResponse {
+    pub total_supply: Option<u64>,
+    /// Sum of open position vault balances.
+    pub total_collateral: u64,
+    /// Sum of open position debt.
+    pub total_debt: u64,
+    pub position_count: usize,
+    /// SOL/USD price as a decimal string.
+    pub price: Option<String>,
+    pub system_collateral_ratio_bps: Option<u64>,
+    pub config: Option<crate::db::ConfigRow>,
+}
+
+fn checked_sum(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(0u64, u64::checked_add)
+}
+
+async fn protocol(State(indexer): State<Arc<Indexer>>) -> ApiResult<ProtocolResponse> {
+    let positions = indexer.db.open_positions()?;
+    let total_collateral = checked_sum(positions.iter().map(|p| p.snapshot.collateral))
+        .ok_or_else(|| anyhow::anyhow!("total collateral overflows u64"))?;
+    let total_debt = checked_sum(positions.iter().map(|p| p.snapshot.amount_minted))
+        .ok_or_else(|| anyhow::anyhow!("total debt overflows u64"))?;
+    let price = indexer.db.latest_price()?;
+    let system_collateral_ratio_bps = price
+        .filter(|_| total_debt > 0)
+        .map(|price| calculate_health_factor(total_collateral, total_debt, price))
+        .transpose()
+        .map_err(anyhow::Error::from)?
+        .map(health_factor_bps);
+
+    Ok(Json(ProtocolResponse {
+        total_supply: indexer.db.latest_supply()?.map(|supply| supply.supply),
+        total_collateral,
+        total_debt,
+        position_count: positions.len(),
+        price: price.map(|price| price.to_string()),
+        system_collateral_ratio_bps,
+        config: indexer.db.config()?,
+    }))
+}
+
+async fn supply_history(State(indexer): State<Arc<Indexer>>) -> ApiResult<Vec<SupplyRow>> {
+    Ok(Json(indexer.db.supply_history()?))
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct HealthFactorBucket {
+    pub min_bps: u64,
+    /// Exclusive upper bound, `None` for the last bucket.
+    pub max_bps: Option<u64>,
+    pub count: usize,
+    /// Summed in `u128`, as the sum across positions can exceed `u64`.
+    pub collateral: u128,
+    pub debt: u128,
+}
+
+/// Groups positions with debt into `HEALTH_FACTOR_BUCKETS_BPS` by health factor at `price`.
+pub fn health_factor_distribution(
+    positions: &[PositionRow],
+    price: Wad,
+) -> anyhow::Result<Vec<HealthFactorBucket>> {
+    let mut buckets = HEALTH_FACTOR_BUCKETS_BPS
+        .iter()
+        .enumerate()
+        .map(|(i, &min_bps)| HealthFactorBucket {
+            min_bps,
+            max_bps: HEALTH_FACTOR_BUCKETS_BPS.get(i + 1).copied(),
+            count: 0,
+            collateral: 0,
+            debt: 0,
+        })
+        .collect::<Vec<_>>();
+
+    for position in positions {
+        let position = &position.snapshot;
+
+        if position.amount_minted == 0 {
+            continue;
+        }
+
+        let bps = health_factor_bps(position.health_factor(price)?);
+        let bucket = buckets
+            .iter_mut()
+            .rev()
+            .find(|bucket| bps >= bucket.min_bps)
+            .expect("first bucket starts at 0");
+
+        bucket.count += 1;
+        bucket.collateral += u128::from(position.collateral);
+        bucket.debt += u128::from(position.amount_minted);
+    }
+
+    Ok(buckets)
+}
+
+#[derive(Serialize)]
+pub struct HealthFactorsResponse {
+    pub price: String,
+    /// Positions below the liquidation threshold, `None` before the config is indexed.
+    pub liquidatable: Option<usize>,
+    pub buckets: Vec<HealthFactorBucket>,
+}
+
+async fn health_factors(State(indexer): State<Arc<Indexer>>) -> ApiResult<HealthFactorsResponse> {
+    let price = indexer.db.latest_price()?.ok_or_else(|| {
+        ApiError(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "no price indexed".to_string(),
+        )
+    })?;
+    let positions = indexer.db.open_positions()?;
+    let liquidatable = indexer
+        .db
+        .config()?
+        .map(|config| {
+            let threshold = Wad::from_bps(config.liquidation_threshold_bps);
+
+            positions
+                .iter()
+                .filter(|p| p.snapshot.amount_minted > 0)
+                .map(|p| p.snapshot.health_factor(price))
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map(|health_factors| {
+                    health_factors
+                        .into_iter()
+                        .filter(|hf| *hf < threshold)
+                        .count()
+                })
+        })
+        .transpose()?;
+
+    Ok(Json(HealthFactorsResponse {
+        price: price.to_string(),
+        liquidatable,
+        buckets: health_factor_distribution(&positions, price)?,
+    }))
+}
+
+#[derive(Deserialize)]
+struct EventsQuery {
+    limit: Option<u32>,
+}
+
+async fn events(
+    State(indexer): State<Arc<Indexer>>,
+    Query(query): Query<EventsQuery>,
+) -> ApiResult<Vec<EventRow>> {
+    Ok(Json(
+        indexer.db.events(query.limit.unwrap_or(100).min(1000))?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use stablecoin_client::PositionSnapshot;
+
+    use super::*;
+
+    fn position(collateral: u64, amount_minted: u64) -> PositionRow {
+        PositionRow {
+            snapshot: PositionSnapshot {
+                address: String::new(),
+                depositor: String::new(),
+                collateral,
+                amount_minted,
+                auction_ends_at: 0,
+            },
+            closed: false,
+            slot: 0,
+        }
+    }
+
+    #[test]
+    fn buckets_health_factors() {
+        let price = Wad(100 * stablecoin::WAD);
+        let positions = [
+            // 1 SOL at $100 against $120 debt, 83.33%
+            position(1_000_000_000, 120_000_000),
+            // 1 SOL at $100 against $80 debt, 125%
+            position(1_000_000_000, 80_000_000),
+            // no debt
+            position(1_000_000_000, 0),
+            // 10 SOL at $100 against $100 debt, 1000%
+            position(10_000_000_000, 100_000_000),
+        ];
+
+        let counts = health_factor_distribution(&positions, price)
+            .unwrap()
+            .into_iter()
+            .map(|bucket| (bucket.min_bps, bucket.count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            counts,
+            vec![
+                (0, 1),
+                (10000, 0),
+                (11000, 0),
+                (12500, 1),
+                (15000, 0),
+                (20000, 0),
+                (30000, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn sums_bucket_amounts_beyond_u64() {
+        let price = Wad(100 * stablecoin::WAD);
+        let positions = [position(u64::MAX, 1), position(u64::MAX, 1)];
+
+        let bucket = health_factor_distribution(&positions, price)
+            .unwrap()
+            .into_iter()
+            .find(|bucket| bucket.count > 0)
+            .unwrap();
+
+        assert_eq!(bucket.count, 2);
+        assert_eq!(bucket.collateral, 2 * u128::from(u64::MAX));
+        assert_eq!(bucket.debt, 2);
+    }
+
+    #[test]
+    fn checked_sum_detects_overflow() {
+        assert_eq!(checked_sum([1, 2, 3]), Some(6));
+        assert_eq!(checked_sum([u64::MAX, 1]), None);
+    }
+}
@@ -0,0 +1,433 @@
+use std::{
+    path::Path,
+    sync::{Mutex, MutexGuard},
+};
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use stablecoin::{Config, Wad};
+use stablecoin_client::PositionSnapshot;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS positions (
+    address TEXT PRIMARY KEY,
+    depositor TEXT NOT NULL,
+    vault TEXT NOT NULL UNIQUE,
+    amount_minted INTEGER NOT NULL,
+    auction_ends_at INTEGER NOT NULL,
+    staked_collateral INTEGER NOT NULL DEFAULT 0,
+    closed INTEGER NOT NULL DEFAULT 0,
+    slot INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS vault_balances (
+    vault TEXT PRIMARY KEY,
+    lamports INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS position_history (
+    address TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    collateral INTEGER NOT NULL,
+    amount_minted INTEGER NOT NULL,
+    auction_ends_at INTEGER NOT NULL,
+    closed INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS position_history_address ON position_history (address, slot);
+CREATE TABLE IF NOT EXISTS config (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    slot INTEGER NOT NULL,
+    min_loan_to_value_bps INTEGER NOT NULL,
+    liquidation_threshold_bps INTEGER NOT NULL,
+    critical_collateral_ratio_bps INTEGER NOT NULL,
+    total_collateral INTEGER NOT NULL,
+    total_debt INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS supply_history (
+    slot INTEGER NOT NULL,
+    supply INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS price_history (
+    slot INTEGER NOT NULL,
+    price TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS events (
+    signature TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    idx INTEGER NOT NULL,
+    discriminator TEXT NOT NULL,
+    data TEXT NOT NULL,
+    PRIMARY KEY (signature, idx)
+);
+";
+
+/// Latest state of an indexed position.
+#[derive(Clone, Debug, Serialize)]
+pub struct PositionRow {
+    #[serde(flatten)]
+    pub snapshot: PositionSnapshot,
+    pub closed: bool,
+    pub slot: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PositionHistoryRow {
+    pub slot: u64,
+    pub collateral: u64,
+    pub amount_minted: u64,
+    pub auction_ends_at: i64,
+    pub closed: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConfigRow {
+    pub slot: u64,
+    pub min_loan_to_value_bps: u16,
+    pub liquidation_threshold_bps: u16,
+    pub critical_collateral_ratio_bps: u16,
+    pub total_collateral: u64,
+    pub total_debt: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SupplyRow {
+    pub slot: u64,
+    pub supply: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventRow {
+    pub signature: String,
+    pub slot: u64,
+    pub index: u32,
+    pub discriminator: String,
+    pub data: String,
+}
+
+pub struct Database {
+    conn: Mutex<Connection>,
+}
+
+impl Database {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap()
+    }
+
+    /// Stores a position. `snapshot` is built without the vault balance, which is tracked
+    /// separately, so its collateral is the staked amount.
+    pub fn upsert_position(
+        &self,
+        snapshot: &PositionSnapshot,
+        vault: &str,
+        slot: u64,
+    ) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO positions (address, depositor, vault, amount_minted, auction_ends_at, staked_collateral, closed, slot)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7)
+             ON CONFLICT (address) DO UPDATE SET
+                amount_minted = excluded.amount_minted,
+                auction_ends_at = excluded.auction_ends_at,
+                staked_collateral = excluded.staked_collateral,
+                closed = 0,
+                slot = excluded.slot",
+            params![
+                snapshot.address,
+                snapshot.depositor,
+                vault,
+                snapshot.amount_minted,
+                snapshot.auction_ends_at,
+                snapshot.collateral,
+                slot
+            ],
+        )?;
+
+        self.record_position_history(&snapshot.address, slot)
+    }
+
+    /// Marks a position as closed, returning whether it was indexed.
+    pub fn close_position(&self, address: &str, slot: u64) -> Result<bool> {
+        let updated = self.conn().execute(
+            "UPDATE positions SET amount_minted = 0, closed = 1, slot = ?2
+             WHERE address = ?1 AND closed = 0",
+            params![address, slot],
+        )?;
+
+        if updated > 0 {
+            self.record_position_history(address, slot)?;
+        }
+
+        Ok(updated > 0)
+    }
+
+    /// Stores the balance of a vault, returning the address of its position if indexed.
+    pub fn set_vault_balance(
+        &self,
+        vault: &str,
+        lamports: u64,
+        slot: u64,
+    ) -> Result<Option<String>> {
+        self.conn().execute(
+            "INSERT INTO vault_balances (vault, lamports, slot) VALUES (?1, ?2, ?3)
+             ON CONFLICT (vault) DO UPDATE SET lamports = excluded.lamports, slot = excluded.slot",
+            params![vault, lamports, slot],
+        )?;
+
+        let address = self
+            .conn()
+            .query_row(
+                "SELECT address FROM positions WHERE vault = ?1",
+                params![vault],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?;
+
+        if let Some(address) = &address {
+            self.record_position_history(address, slot)?;
+        }
+
+        Ok(address)
+    }
+
+    pub fn is_vault(&self, vault: &str) -> Result<bool> {
+        Ok(self
+            .conn()
+            .query_row(
+                "SELECT 1 FROM positions WHERE vault = ?1",
+                params![vault],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some())
+    }
+
+    fn record_position_history(&self, address: &str, slot: u64) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO position_history (address, slot, collateral, amount_minted, auction_ends_at, closed)
+             SELECT p.address, ?2, COALESCE(v.lamports, 0) + p.staked_collateral, p.amount_minted, p.auction_ends_at, p.closed
+             FROM positions p LEFT JOIN vault_balances v ON v.vault = p.vault
+             WHERE p.address = ?1",
+            params![address, slot],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_config(&self, config: &Config, slot: u64) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO config (id, slot, min_loan_to_value_bps, liquidation_threshold_bps,
+                critical_collateral_ratio_bps, total_collateral, total_debt)
+             VALUES (0, ?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (id) DO UPDATE SET
+                slot = excluded.slot,
+                min_loan_to_value_bps = excluded.min_loan_to_value_bps,
+                liquidation_threshold_bps = excluded.liquidation_threshold_bps,
+                critical_collateral_ratio_bps = excluded.critical_collateral_ratio_bps,
+                total_collateral = excluded.total_collateral,
+                total_debt = excluded.total_debt",
+            params![
+                slot,
+                config.min_loan_to_value_bps,
+                config.liquidation_threshold_bps,
+                config.critical_collateral_ratio_bps,
+                config.total_collateral,
+                config.total_debt
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn record_supply(&self, supply: u64, slot: u64) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO supply_history (slot, supply) VALUES (?1, ?2)",
+            params![slot, supply],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn record_price(&self, price: Wad, slot: u64) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO price_history (slot, price) VALUES (?1, ?2)",
+            params![slot, price.0.to_string()],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn record_event(&self, event: &EventRow) -> Result<()> {
+        self.conn().execute(
+            "INSERT OR IGNORE INTO events (signature, slot, idx, discriminator, data)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                event.signature,
+                event.slot,
+                event.index,
+                event.discriminator,
+                event.data
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn positions(&self) -> Result<Vec<PositionRow>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT p.address, p.depositor, COALESCE(v.lamports, 0) + p.staked_collateral, p.amount_minted,
+                p.auction_ends_at, p.closed, p.slot
+             FROM positions p LEFT JOIN vault_balances v ON v.vault = p.vault
+             ORDER BY p.address",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(PositionRow {
+                snapshot: PositionSnapshot {
+                    address: row.get(0)?,
+                    depositor: row.get(1)?,
+                    collateral: row.get(2)?,
+                    amount_minted: row.get(3)?,
+                    auction_ends_at: row.get(4)?,
+                },
+                closed: row.get(5)?,
+                slot: row.get(6)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub fn open_positions(&self) -> Result<Vec<PositionRow>> {
+        Ok(self
+            .positions()?
+            .into_iter()
+            .filter(|position| !position.closed)
+            .collect())
+    }
+
+    pub fn position(&self, address: &str) -> Result<Option<PositionRow>> {
+        Ok(self
+            .positions()?
+            .into_iter()
+            .find(|position| position.snapshot.address == address))
+    }
+
+    pub fn position_history(&self, address: &str) -> Result<Vec<PositionHistoryRow>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT slot, collateral, amount_minted, auction_ends_at, closed
+             FROM position_history WHERE address = ?1 ORDER BY slot, rowid",
+        )?;
+        let rows = stmt.query_map(params![address], |row| {
+            Ok(PositionHistoryRow {
+                slot: row.get(0)?,
+                collateral: row.get(1)?,
+                amount_minted: row.get(2)?,
+                auction_ends_at: row.get(3)?,
+                closed: row.get(4)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub fn config(&self) -> Result<Option<ConfigRow>> {
+        Ok(self
+            .conn()
+            .query_row(
+                "SELECT slot, min_loan_to_value_bps, liquidation_threshold_bps,
+                    critical_collateral_ratio_bps, total_collateral, total_debt
+                 FROM config WHERE id = 0",
+                [],
+                |row| {
+                    Ok(ConfigRow {
+                        slot: row.get(0)?,
+                        min_loan_to_value_bps: row.get(1)?,
+                        liquidation_threshold_bps: row.get(2)?,
+                        critical_collateral_ratio_bps: row.get(3)?,
+                        total_collateral: row.get(4)?,
+                        total_debt: row.get(5)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    pub fn supply_history(&self) -> Result<Vec<SupplyRow>> {
+        let conn = self.conn();
+        let mut stmt =
+            conn.prepare("SELECT slot, supply FROM supply_history ORDER BY slot, rowid")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(SupplyRow {
+                slot: row.get(0)?,
+                supply: row.get(1)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub fn latest_supply(&self) -> Result<Option<SupplyRow>> {
+        Ok(self
+            .conn()
+            .query_row(
+                "SELECT slot, supply FROM supply_history ORDER BY rowid DESC LIMIT 1",
+                [],
+                |row| {
+                    Ok(SupplyRow {
+                        slot: row.get(0)?,
+                        supply: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    pub fn latest_price(&self) -> Result<Option<Wad>> {
+        let price = self
+            .conn()
+            .query_row(
+                "SELECT price FROM price_history ORDER BY rowid DESC LIMIT 1",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?;
+
+        Ok(price.map(|price| price.parse().map(Wad)).transpose()?)
+    }
+
+    pub fn events(&self, limit: u32) -> Result<Vec<EventRow>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT signature, slot, idx, discriminator, data
+             FROM events ORDER BY slot DESC, signature, idx LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![limit], |row| {
+            Ok(EventRow {
+                signature: row.get(0)?,
+                slot: row.get(1)?,
+                index: row.get(2)?,
+                discriminator: row.get(3)?,
+                data: row.get(4)?,
+            })
+        })?;
+
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
@@ -0,0 +1,252 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use stablecoin::Wad;
+use stablecoin_client::{
+    mint_pda, price_from_quote_data, vault_pda, AccountFixture, PositionSnapshot, ProgramAccount,
+};
+use tracing::warn;
+
+use crate::db::{Database, EventRow};
+
+/// Account state observed at `slot`. Replay files hold one update per line.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccountUpdate {
+    pub slot: u64,
+    #[serde(flatten)]
+    pub account: AccountFixture,
+}
+
+pub struct Indexer {
+    pub db: Database,
+    /// Switchboard quote account whose updates set the price, ignored if `None`.
+    pub oracle_quote: Option<Pubkey>,
+}
+
+impl Indexer {
+    pub fn new(db: Database, oracle_quote: Option<Pubkey>) -> Self {
+        Self { db, oracle_quote }
+    }
+
+    /// Applies an account update, returning the vault to refresh if a position changed.
+    pub fn apply(&self, update: &AccountUpdate) -> Result<Option<Pubkey>> {
+        let AccountUpdate { slot, account } = update;
+        let slot = *slot;
+        let pubkey = account.pubkey()?;
+        let owner = account.owner()?;
+        let data = account.data()?;
+
+        if Some(pubkey) == self.oracle_quote {
+            match price_from_quote_data(&data) {
+                Ok(price) => self.db.record_price(price, slot)?,
+                Err(err) => warn!(%pubkey, "skipping oracle quote: {err}"),
+            }
+
+            return Ok(None);
+        }
+
+        if pubkey == mint_pda() {
+            self.db
+                .record_supply(stablecoin_client::decode_mint_supply(&data)?, slot)?;
+
+            return Ok(None);
+        }
+
+        if owner != stablecoin::ID || data.is_empty() {
+            let address = pubkey.to_string();
+
+            // closed positions are reassigned to the system program
+            if self.db.close_position(&address, slot)? {
+                return Ok(None);
+            }
+
+            if self.db.is_vault(&address)? {
+                self.db
+                    .set_vault_balance(&address, account.account.lamports, slot)?;
+            }
+
+            return Ok(None);
+        }
+
+        match ProgramAccount::decode(&data) {
+            Ok(Some(ProgramAccount::Config(config))) => self.db.set_config(&config, slot)?,
+            Ok(Some(ProgramAccount::Position(position))) => {
+                let vault = vault_pda(&pubkey);
+                let snapshot = PositionSnapshot::new(&pubkey, &position, 0);
+
+                self.db
+                    .upsert_position(&snapshot, &vault.to_string(), slot)?;
+
+                return Ok(Some(vault));
+            }
+            Ok(None) => {}
+            // accounts predating layout versioning are indexed once migrated
+            Err(err) => warn!(%pubkey, "skipping undecodable account: {err}"),
+        }
+
+        Ok(None)
+    }
+
+    pub fn set_price(&self, price: Wad, slot: u64) -> Result<()> {
+        self.db.record_price(price, slot)
+    }
+
+    /// Records events emitted by the stablecoin program in a transaction's logs.
+    pub fn apply_logs(&self, signature: &str, slot: u64, logs: &[String]) -> Result<()> {
+        for (index, data) in program_data(logs).into_iter().enumerate() {
+            let Ok(data) = STANDARD.decode(data) else {
+                continue;
+            };
+            let discriminator = data
+                .iter()
+                .take(8)
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+
+            self.db.record_event(&EventRow {
+                signature: signature.to_string(),
+                slot,
+                index: index as u32,
+                discriminator,
+                data: STANDARD.encode(&data),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Extracts `Program data:` payloads logged by the stablecoin program itself, skipping those of
+/// programs it invokes.
+pub fn program_data(logs: &[String]) -> Vec<&str> {
+    let program_id = stablecoin::ID.to_string();
+    let mut stack: Vec<&str> = vec![];
+    let mut data = vec![];
+
+    for log in logs {
+        let Some(log) = log.strip_prefix("Program ") else {
+            continue;
+        };
+
+        if let Some(payload) = log.strip_prefix("data: ") {
+            if stack.last() == Some(&program_id.as_str()) {
+                data.push(payload);
+            }
+        } else if let Some((program, rest)) = log.split_once(' ') {
+            if rest.starts_with("invoke [") {
+                stack.push(program);
+            } else if rest == "success" || rest.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{AccountSerialize, Space};
+    use stablecoin::{Position, POSITION_VERSION};
+    use stablecoin_client::position_pda;
+
+    use super::*;
+
+    fn position_update(slot: u64, depositor: &Pubkey, amount_minted: u64) -> AccountUpdate {
+        let position = Position {
+            version: POSITION_VERSION,
+            depositor: *depositor,
+            amount_minted,
+            auction_ends_at: 0,
+            bump: 255,
+            vault_bump: 255,
+            staked_collateral: 0,
+            stake_bump: 0,
+            reserved: [0; 55],
+        };
+        let mut data = Vec::with_capacity(8 + Position::INIT_SPACE);
+
+        position.try_serialize(&mut data).unwrap();
+
+        AccountUpdate {
+            slot,
+            account: AccountFixture::new(
+                &position_pda(depositor),
+                1_000_000,
+                &data,
+                &stablecoin::ID,
+            ),
+        }
+    }
+
+    fn system_update(slot: u64, pubkey: &Pubkey, lamports: u64) -> AccountUpdate {
+        AccountUpdate {
+            slot,
+            account: AccountFixture::new(pubkey, lamports, &[], &Pubkey::default()),
+        }
+    }
+
+    #[test]
+    fn tracks_position_history() {
+        let indexer = Indexer::new(Database::open_in_memory().unwrap(), None);
+        let depositor = Pubkey::new_unique();
+        let position = position_pda(&depositor);
+        let vault = vault_pda(&position);
+
+        assert_eq!(
+            indexer.apply(&position_update(1, &depositor, 100)).unwrap(),
+            Some(vault)
+        );
+        indexer.apply(&system_update(1, &vault, 5_000)).unwrap();
+        indexer.apply(&position_update(2, &depositor, 50)).unwrap();
+        indexer.apply(&system_update(3, &position, 0)).unwrap();
+        indexer.apply(&system_update(3, &vault, 0)).unwrap();
+
+        let history = indexer
+            .db
+            .position_history(&position.to_string())
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.slot, row.collateral, row.amount_minted, row.closed))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            history,
+            vec![
+                (1, 0, 100, false),
+                (1, 5_000, 100, false),
+                (2, 5_000, 50, false),
+                (3, 5_000, 0, true),
+                (3, 0, 0, true),
+            ]
+        );
+        assert!(indexer.db.open_positions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn ignores_unknown_system_accounts() {
+        let indexer = Indexer::new(Database::open_in_memory().unwrap(), None);
+
+        indexer
+            .apply(&system_update(1, &Pubkey::new_unique(), 5_000))
+            .unwrap();
+
+        assert!(indexer.db.positions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn extracts_top_level_program_data() {
+        let program_id = stablecoin::ID.to_string();
+        let logs = [
+            format!("Program {program_id} invoke [1]"),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]".to_string(),
+            "Program data: aW5uZXI=".to_string(),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success".to_string(),
+            "Program data: b3V0ZXI=".to_string(),
+            format!("Program {program_id} success"),
+        ];
+
+        assert_eq!(program_data(&logs), vec!["b3V0ZXI="]);
+    }
+}
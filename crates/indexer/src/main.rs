@@ -0,0 +1,103 @@
+//! Indexes stablecoin program accounts into SQLite and serves positions and protocol metrics
+//! over a JSON API.
+
+mod api;
+mod db;
+mod indexer;
+mod source;
+
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::Parser;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+use stablecoin_client::parse_price;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+use tracing_subscriber::EnvFilter;
+
+use crate::{db::Database, indexer::Indexer};
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// SQLite database path, created if missing.
+    #[arg(long, default_value = "indexer.sqlite")]
+    db: PathBuf,
+    /// Address the HTTP API listens on.
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    listen: SocketAddr,
+    #[arg(long, env = "RPC_URL", default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    #[arg(long, env = "WS_URL", default_value = "ws://127.0.0.1:8900")]
+    ws_url: String,
+    /// Replays account updates from a JSON Lines file instead of subscribing to RPC.
+    #[arg(long)]
+    replay: Option<PathBuf>,
+    /// Switchboard SOL/USD quote account to read prices from.
+    #[arg(long)]
+    oracle_quote: Option<Pubkey>,
+    /// Fixed SOL/USD price used for health factors, e.g. `150.25`.
+    #[arg(long, value_parser = parse_price)]
+    price: Option<stablecoin::Wad>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .init();
+
+    let Args {
+        db,
+        listen,
+        rpc_url,
+        ws_url,
+        replay,
+        oracle_quote,
+        price,
+    } = Args::parse();
+    let indexer = Arc::new(Indexer::new(
+        Database::open(db)?,
+        // a fixed price takes precedence over oracle updates
+        oracle_quote.filter(|_| price.is_none()),
+    ));
+
+    if let Some(price) = price {
+        indexer.set_price(price, 0)?;
+    }
+
+    if let Some(replay) = replay {
+        let count = source::replay(&indexer, replay)?;
+
+        info!(count, "replayed account updates");
+    } else {
+        let indexer = indexer.clone();
+
+        tokio::spawn(async move {
+            let rpc = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+
+            loop {
+                let result = match source::sync(&indexer, &rpc).await {
+                    Ok(()) => source::subscribe(&indexer, &rpc, &ws_url).await,
+                    Err(err) => Err(err),
+                };
+
+                if let Err(err) = result {
+                    error!("indexing interrupted, resyncing: {err:#}");
+                }
+
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+        });
+    }
+
+    let listener = TcpListener::bind(listen).await?;
+
+    info!(%listen, "serving API");
+    axum::serve(listener, api::router(indexer)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use std::{fs, path::Path, str::FromStr};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Context, Result};
+use futures_util::{stream::select_all, StreamExt};
+use solana_account_decoder_client_types::{UiAccount, UiAccountEncoding};
+use solana_client::{
+    nonblocking::{pubsub_client::PubsubClient, rpc_client::RpcClient},
+    rpc_config::{
+        RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionLogsConfig,
+        RpcTransactionLogsFilter,
+    },
+};
+use stablecoin_client::{mint_pda, AccountFixture};
+use tracing::{info, warn};
+
+use crate::indexer::{AccountUpdate, Indexer};
+
+/// Applies every update of a JSON Lines replay file in order.
+pub fn replay(indexer: &Indexer, path: impl AsRef<Path>) -> Result<usize> {
+    let path = path.as_ref();
+    let updates = fs::read_to_string(path).with_context(|| format!("reading {path:?}"))?;
+    let mut count = 0;
+
+    for (line, update) in updates.lines().enumerate() {
+        if update.trim().is_empty() {
+            continue;
+        }
+
+        let update: AccountUpdate = serde_json::from_str(update)
+            .with_context(|| format!("parsing {path:?} line {}", line + 1))?;
+
+        indexer.apply(&update)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+fn to_update(slot: u64, pubkey: &str, account: &UiAccount) -> Result<AccountUpdate> {
+    let data = account
+        .data
+        .decode()
+        .ok_or_else(|| anyhow!("undecodable data for {pubkey}"))?;
+
+    Ok(AccountUpdate {
+        slot,
+        account: AccountFixture::new(
+            &Pubkey::from_str(pubkey)?,
+            account.lamports,
+            &data,
+            &Pubkey::from_str(&account.owner)?,
+        ),
+    })
+}
+
+/// Fetches the current lamports of `vault` as an update.
+async fn vault_update(rpc: &RpcClient, vault: &Pubkey) -> Result<AccountUpdate> {
+    let response = rpc
+        .get_account_with_commitment(vault, rpc.commitment())
+        .await?;
+    let lamports = response.value.map_or(0, |account| account.lamports);
+
+    Ok(AccountUpdate {
+        slot: response.context.slot,
+        account: AccountFixture::new(vault, lamports, &[], &Pubkey::default()),
+    })
+}
+
+/// Applies `update`, refreshing the vault of any changed position.
+async fn apply(indexer: &Indexer, rpc: &RpcClient, update: &AccountUpdate) -> Result<()> {
+    if let Some(vault) = indexer.apply(update)? {
+        indexer.apply(&vault_update(rpc, &vault).await?)?;
+    }
+
+    Ok(())
+}
+
+/// Loads current program state from RPC.
+pub async fn sync(indexer: &Indexer, rpc: &RpcClient) -> Result<()> {
+    let slot = rpc.get_slot().await?;
+    let accounts = rpc.get_program_accounts(&stablecoin::ID).await?;
+    let mut tracked = vec![mint_pda()];
+
+    tracked.extend(indexer.oracle_quote);
+
+    for (pubkey, account) in &accounts {
+        apply(
+            indexer,
+            rpc,
+            &AccountUpdate {
+                slot,
+                account: AccountFixture::new(
+                    pubkey,
+                    account.lamports,
+                    &account.data,
+                    &account.owner,
+                ),
+            },
+        )
+        .await?;
+    }
+
+    for (pubkey, account) in tracked
+        .iter()
+        .zip(rpc.get_multiple_accounts(&tracked).await?)
+    {
+        let Some(account) = account else {
+            warn!(%pubkey, "account not found");
+            continue;
+        };
+
+        indexer.apply(&AccountUpdate {
+            slot,
+            account: AccountFixture::new(pubkey, account.lamports, &account.data, &account.owner),
+        })?;
+    }
+
+    info!(slot, accounts = accounts.len(), "synced program accounts");
+
+    Ok(())
+}
+
+enum Notification {
+    Account(AccountUpdate),
+    Logs {
+        signature: String,
+        slot: u64,
+        logs: Vec<String>,
+    },
+}
+
+/// Streams program account, mint, oracle quote and log updates into the indexer until the
+/// websocket closes.
+pub async fn subscribe(indexer: &Indexer, rpc: &RpcClient, ws_url: &str) -> Result<()> {
+    let pubsub = PubsubClient::new(ws_url).await?;
+    let account_config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(rpc.commitment()),
+        ..Default::default()
+    };
+
+    let (program_updates, _unsubscribe) = pubsub
+        .program_subscribe(
+            &stablecoin::ID,
+            Some(RpcProgramAccountsConfig {
+                account_config: account_config.clone(),
+                ..Default::default()
+            }),
+        )
+        .await?;
+    let (logs, _unsubscribe) = pubsub
+        .logs_subscribe(
+            RpcTransactionLogsFilter::Mentions(vec![stablecoin::ID.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(rpc.commitment()),
+            },
+        )
+        .await?;
+
+    let mut streams = vec![
+        program_updates
+            .map(|response| {
+                to_update(
+                    response.context.slot,
+                    &response.value.pubkey,
+                    &response.value.account,
+                )
+                .map(Notification::Account)
+            })
+            .boxed(),
+        logs.filter(|response| std::future::ready(response.value.err.is_none()))
+            .map(|response| {
+                Ok(Notification::Logs {
+                    signature: response.value.signature,
+                    slot: response.context.slot,
+                    logs: response.value.logs,
+                })
+            })
+            .boxed(),
+    ];
+
+    let mut tracked = vec![mint_pda()];
+
+    tracked.extend(indexer.oracle_quote);
+
+    for pubkey in tracked {
+        let (updates, _unsubscribe) = pubsub
+            .account_subscribe(&pubkey, Some(account_config.clone()))
+            .await?;
+
+        streams.push(
+            updates
+                .map(move |response| {
+                    to_update(response.context.slot, &pubkey.to_string(), &response.value)
+                        .map(Notification::Account)
+                })
+                .boxed(),
+        );
+    }
+
+    let mut notifications = select_all(streams);
+
+    while let Some(notification) = notifications.next().await {
+        let result = match notification {
+            Ok(Notification::Account(update)) => apply(indexer, rpc, &update).await,
+            Ok(Notification::Logs {
+                signature,
+                slot,
+                logs,
+            }) => indexer.apply_logs(&signature, slot, &logs),
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = result {
+            warn!("skipping notification: {err:#}");
+        }
+    }
+
+    Err(anyhow!("websocket subscription closed"))
+}
@@ -0,0 +1,8 @@
+//! Offline liquidation simulator: replays `liquidate_position` over a snapshot of positions along
+//! historical or synthetic price paths.
+
+pub mod price_path;
+pub use price_path::*;
+
+pub mod simulation;
+pub use simulation::*;
@@ -0,0 +1,151 @@
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+use stablecoin::Wad;
+use stablecoin_client::{parse_price, ProtocolState};
+use stablecoin_simulator::{simulate, PricePath, ScenarioReport, Shock};
+
+/// Replays liquidations of a position snapshot along price paths and reports liquidated volume,
+/// bad debt and liquidator profit per scenario.
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    #[arg(long, env = "RPC_URL", default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    /// Reads accounts from a directory of `solana account --output json` dumps instead of RPC.
+    #[arg(long)]
+    fixtures: Option<PathBuf>,
+    /// Switchboard SOL/USD quote account to read the starting price from.
+    #[arg(long)]
+    oracle_quote: Option<Pubkey>,
+    /// Starting SOL/USD price for shocks, e.g. `150.25`, overriding the oracle quote.
+    #[arg(long, value_parser = parse_price)]
+    price: Option<Wad>,
+    /// CSV file with a `price` column, replayed row by row. Can be repeated.
+    #[arg(long)]
+    csv: Vec<PathBuf>,
+    /// Price change from the starting price as `<PERCENT>[:<STEPS>]`, e.g. `-40:20`. Can be
+    /// repeated.
+    #[arg(long, allow_hyphen_values = true)]
+    shock: Vec<Shock>,
+    #[arg(long)]
+    min_loan_to_value_bps: Option<u16>,
+    #[arg(long)]
+    liquidation_threshold_bps: Option<u16>,
+    #[arg(long)]
+    liquidation_bonus_bps: Option<u16>,
+    #[arg(long)]
+    critical_collateral_ratio_bps: Option<u16>,
+    #[arg(long)]
+    treasury_fee_bps: Option<u16>,
+    /// Prints reports as JSON instead of a table.
+    #[arg(long)]
+    json: bool,
+}
+
+fn print_table(reports: &[ScenarioReport]) {
+    println!(
+        "{:<32} {:>6} {:>14} {:>12} {:>18} {:>18} {:>18} {:>18} {:>10} {:>18}",
+        "scenario",
+        "steps",
+        "final price",
+        "liquidations",
+        "debt liquidated",
+        "collateral seized",
+        "treasury fees",
+        "liquidator profit",
+        "underwater",
+        "bad debt"
+    );
+
+    for report in reports {
+        println!(
+            "{:<32} {:>6} {:>14} {:>12} {:>18} {:>18} {:>18} {:>18} {:>10} {:>18}",
+            report.scenario,
+            report.steps,
+            report.final_price,
+            report.liquidations,
+            report.debt_liquidated,
+            report.collateral_seized,
+            report.treasury_fees,
+            report.liquidator_profit,
+            report.underwater_positions,
+            report.bad_debt
+        );
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+
+    if args.csv.is_empty() && args.shock.is_empty() {
+        bail!("at least one --csv or --shock scenario is required");
+    }
+
+    let oracle_quote = args.oracle_quote.as_ref().filter(|_| args.price.is_none());
+    let ProtocolState {
+        mut config,
+        positions,
+        price,
+        ..
+    } = match &args.fixtures {
+        Some(fixtures) => ProtocolState::load_fixtures(fixtures, oracle_quote)?,
+        None => {
+            let rpc =
+                RpcClient::new_with_commitment(args.rpc_url.clone(), CommitmentConfig::confirmed());
+
+            ProtocolState::fetch(&rpc, oracle_quote).await?
+        }
+    };
+
+    if let Some(bps) = args.min_loan_to_value_bps {
+        config.min_loan_to_value_bps = bps;
+    }
+    if let Some(bps) = args.liquidation_threshold_bps {
+        config.liquidation_threshold_bps = bps;
+    }
+    if let Some(bps) = args.liquidation_bonus_bps {
+        config.liquidation_bonus_bps = bps;
+    }
+    if let Some(bps) = args.critical_collateral_ratio_bps {
+        config.critical_collateral_ratio_bps = bps;
+    }
+    if let Some(bps) = args.treasury_fee_bps {
+        config.treasury_fee_bps = bps;
+    }
+
+    let mut paths = args
+        .csv
+        .iter()
+        .map(PricePath::load_csv)
+        .collect::<Result<Vec<_>>>()?;
+
+    if !args.shock.is_empty() {
+        let start = args
+            .price
+            .or(price)
+            .ok_or_else(|| anyhow!("either --price or --oracle-quote is required for shocks"))?;
+
+        for shock in &args.shock {
+            paths.push(shock.path(start)?);
+        }
+    }
+
+    let reports = paths
+        .iter()
+        .map(|path| simulate(&config, &positions, path))
+        .collect::<Result<Vec<_>>>()?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    } else {
+        print_table(&reports);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::{path::Path, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Result};
+use stablecoin::{Wad, MAX_BASIS_POINTS};
+use stablecoin_client::parse_price;
+
+/// Sequence of SOL/USD prices applied one step at a time.
+#[derive(Clone, Debug)]
+pub struct PricePath {
+    pub name: String,
+    pub prices: Vec<Wad>,
+}
+
+impl PricePath {
+    /// Loads prices from the `price` column of a CSV file, in row order.
+    pub fn load_csv(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut reader =
+            csv::Reader::from_path(path).with_context(|| format!("reading {path:?}"))?;
+        let column = reader
+            .headers()?
+            .iter()
+            .position(|header| header.trim() == "price")
+            .ok_or_else(|| anyhow!("{path:?} has no price column"))?;
+        let mut prices = vec![];
+
+        for (row, record) in reader.records().enumerate() {
+            let record = record?;
+            let price = record
+                .get(column)
+                .ok_or_else(|| anyhow!("{path:?} row {} has no price", row + 1))?;
+
+            prices.push(parse_price(price.trim())?);
+        }
+
+        if prices.is_empty() {
+            bail!("{path:?} has no prices");
+        }
+
+        Ok(Self {
+            name: path.file_stem().map_or_else(
+                || path.display().to_string(),
+                |stem| stem.to_string_lossy().into_owned(),
+            ),
+            prices,
+        })
+    }
+}
+
+/// Synthetic price move of `change_bps` from the starting price, applied linearly over `steps`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Shock {
+    pub change_bps: i64,
+    pub steps: u32,
+}
+
+impl Shock {
+    pub fn path(&self, start: Wad) -> Result<PricePath> {
+        let prices = (1..=i64::from(self.steps))
+            .map(|step| {
+                let multiplier_bps =
+                    i64::from(MAX_BASIS_POINTS) + self.change_bps * step / i64::from(self.steps);
+                let multiplier_bps = u128::try_from(multiplier_bps).map_err(|_| {
+                    anyhow!("shock of {} bps drives price negative", self.change_bps)
+                })?;
+
+                start
+                    .0
+                    .checked_mul(multiplier_bps)
+                    .map(|price| Wad(price / u128::from(MAX_BASIS_POINTS)))
+                    .ok_or_else(|| anyhow!("price overflow"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(PricePath {
+            name: format!(
+                "shock {}% over {} steps",
+                self.change_bps as f64 / 100.0,
+                self.steps
+            ),
+            prices,
+        })
+    }
+}
+
+impl FromStr for Shock {
+    type Err = anyhow::Error;
+
+    /// Parses `<PERCENT>[:<STEPS>]`, e.g. `-40` or `-40:20`.
+    fn from_str(shock: &str) -> Result<Self> {
+        let (percent, steps) = shock.split_once(':').unwrap_or((shock, "1"));
+        let percent: f64 = percent.parse()?;
+        let steps: u32 = steps.parse()?;
+
+        if steps == 0 || percent <= -100.0 {
+            bail!("invalid shock {shock}");
+        }
+
+        Ok(Self {
+            change_bps: (percent * 100.0).round() as i64,
+            steps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stablecoin::WAD;
+
+    use super::*;
+
+    #[test]
+    fn shock_decays_linearly() {
+        let shock: Shock = "-40:4".parse().unwrap();
+        let prices = shock.path(Wad(100 * WAD)).unwrap().prices;
+
+        assert_eq!(
+            prices,
+            [90, 80, 70, 60].map(|price| Wad(price * WAD)).to_vec()
+        );
+    }
+
+    #[test]
+    fn parses_shocks() {
+        assert_eq!(
+            "-12.5".parse::<Shock>().unwrap(),
+            Shock {
+                change_bps: -1250,
+                steps: 1
+            }
+        );
+        assert!("-100".parse::<Shock>().is_err());
+        assert!("-10:0".parse::<Shock>().is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use anyhow::Result;
+use serde::Serialize;
+use stablecoin::{
+    calculate_collateral_value, calculate_health_factor, calculate_liquidation, Config,
+    Liquidation, Wad,
+};
+use stablecoin_client::PositionSnapshot;
+
+use crate::PricePath;
+
+/// Outcome of replaying liquidations along a price path.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ScenarioReport {
+    pub scenario: String,
+    pub steps: usize,
+    /// SOL/USD price at the last step, as a decimal string.
+    pub final_price: String,
+    pub liquidations: usize,
+    /// Stablecoin burned by liquidators, in base units.
+    pub debt_liquidated: u64,
+    /// Lamports taken from vaults, including the treasury share of the bonus.
+    pub collateral_seized: u64,
+    /// Lamports of the liquidation bonus paid to the treasury.
+    pub treasury_fees: u64,
+    /// Value of collateral paid to liquidators at the liquidation price minus debt burned, in
+    /// stablecoin base units.
+    pub liquidator_profit: i64,
+    /// Positions left unhealthy at the end that no liquidation can restore.
+    pub underwater_positions: usize,
+    /// Debt of underwater positions not covered by their collateral at the final price, in
+    /// stablecoin base units.
+    pub bad_debt: u64,
+}
+
+/// Smallest liquidation that `liquidate_position` accepts for `position` at `price`, or `None` if
+/// the position is healthy or its vault cannot cover even a full liquidation.
+pub fn min_liquidation(
+    config: &Config,
+    position: &PositionSnapshot,
+    price: Wad,
+) -> Option<(u64, Liquidation)> {
+    let PositionSnapshot {
+        collateral,
+        amount_minted,
+        ..
+    } = *position;
+    let liquidate = |amount_to_burn: u64| {
+        calculate_liquidation(config, collateral, amount_minted, amount_to_burn, price)
+            .ok()
+            // the vault transfer fails if seized collateral exceeds its balance
+            .filter(|liquidation| liquidation.collateral_seized <= collateral)
+    };
+
+    // a full liquidation is the largest, so if it fails every smaller one does too
+    let mut best = (amount_minted, liquidate(amount_minted)?);
+    let mut low = 1;
+
+    // remaining health factor grows with the amount burned while the vault covers the bonus
+    while low < best.0 {
+        let mid = low + (best.0 - low) / 2;
+
+        match liquidate(mid) {
+            Some(liquidation) => best = (mid, liquidation),
+            None => low = mid + 1,
+        }
+    }
+
+    Some(best)
+}
+
+/// Replays liquidations of `positions` along `path`, liquidating every eligible position with the
+/// smallest accepted amount at each step.
+pub fn simulate(
+    config: &Config,
+    positions: &[PositionSnapshot],
+    path: &PricePath,
+) -> Result<ScenarioReport> {
+    let mut config = config.clone();
+    let mut positions = positions.to_vec();
+
+    // recovery mode applies to the simulated book rather than the snapshotted totals
+    config.total_collateral = positions.iter().map(|position| position.collateral).sum();
+    config.total_debt = positions
+        .iter()
+        .map(|position| position.amount_minted)
+        .sum();
+    let mut report = ScenarioReport {
+        scenario: path.name.clone(),
+        steps: path.prices.len(),
+        ..Default::default()
+    };
+
+    for &price in &path.prices {
+        let mut closed = vec![];
+
+        for (index, position) in positions.iter_mut().enumerate() {
+            if position.amount_minted == 0 {
+                continue;
+            }
+
+            let Some((amount_to_burn, liquidation)) = min_liquidation(&config, position, price)
+            else {
+                continue;
+            };
+
+            config.decrease_totals(liquidation.collateral_seized, amount_to_burn)?;
+
+            report.liquidations += 1;
+            report.debt_liquidated += amount_to_burn;
+            report.collateral_seized += liquidation.collateral_seized;
+            report.treasury_fees += liquidation.treasury_fee;
+            report.liquidator_profit += calculate_collateral_value(
+                liquidation.collateral_seized - liquidation.treasury_fee,
+                price,
+            )? as i64
+                - amount_to_burn as i64;
+
+            position.collateral = liquidation.remaining_collateral;
+            position.amount_minted = liquidation.remaining_debt;
+
+            // residual collateral is returned to the depositor and the position closed
+            if position.amount_minted == 0 {
+                config.decrease_totals(position.collateral, 0)?;
+                closed.push(index);
+            }
+        }
+
+        for index in closed.into_iter().rev() {
+            positions.swap_remove(index);
+        }
+    }
+
+    let Some(&final_price) = path.prices.last() else {
+        return Ok(report);
+    };
+
+    report.final_price = final_price.to_string();
+
+    let recovery_mode = config.is_recovery_mode(final_price)?;
+    let threshold = Wad::from_bps(config.effective_liquidation_threshold_bps(recovery_mode));
+
+    for position in &positions {
+        let health_factor =
+            calculate_health_factor(position.collateral, position.amount_minted, final_price)?;
+
+        if health_factor < threshold {
+            report.underwater_positions += 1;
+            report.bad_debt += position
+                .amount_minted
+                .saturating_sub(calculate_collateral_value(
+                    position.collateral,
+                    final_price,
+                )?);
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{AnchorDeserialize, Space};
+    use stablecoin::WAD;
+
+    use super::*;
+    use crate::Shock;
+
+    fn config() -> Config {
+        let mut config = Config::deserialize(&mut &[0; Config::INIT_SPACE][..]).unwrap();
+
+        config.min_loan_to_value_bps = 15000;
+        config.liquidation_threshold_bps = 12500;
+        config.liquidation_bonus_bps = 500;
+
+        config
+    }
+
+    // 1 SOL against $60 debt, 166% at $100
+    fn position() -> PositionSnapshot {
+        PositionSnapshot {
+            address: String::new(),
+            depositor: String::new(),
+            collateral: 1_000_000_000,
+            amount_minted: 60_000_000,
+            auction_ends_at: 0,
+        }
+    }
+
+    fn run(shock: &str) -> ScenarioReport {
+        let path = shock
+            .parse::<Shock>()
+            .unwrap()
+            .path(Wad(100 * WAD))
+            .unwrap();
+
+        simulate(&config(), &[position()], &path).unwrap()
+    }
+
+    #[test]
+    fn healthy_positions_are_not_liquidated() {
+        let report = run("-10");
+
+        assert_eq!(report.liquidations, 0);
+        assert_eq!(report.bad_debt, 0);
+    }
+
+    #[test]
+    fn liquidates_minimum_amount_to_restore_min_ltv() {
+        let config = config();
+        let price = Wad(70 * WAD);
+        let (amount_to_burn, liquidation) = min_liquidation(&config, &position(), price).unwrap();
+
+        assert!(amount_to_burn < position().amount_minted);
+        assert!(calculate_liquidation(
+            &config,
+            position().collateral,
+            position().amount_minted,
+            amount_to_burn - 1,
+            price
+        )
+        .is_err());
+
+        let report = run("-30");
+
+        assert_eq!(report.liquidations, 1);
+        assert_eq!(report.debt_liquidated, amount_to_burn);
+        assert_eq!(report.collateral_seized, liquidation.collateral_seized);
+        assert!(report.liquidator_profit > 0);
+        assert_eq!(report.bad_debt, 0);
+    }
+
+    #[test]
+    fn treasury_fee_reduces_liquidator_profit() {
+        let path = "-30"
+            .parse::<Shock>()
+            .unwrap()
+            .path(Wad(100 * WAD))
+            .unwrap();
+        let mut config = config();
+        let report = simulate(&config, &[position()], &path).unwrap();
+
+        config.treasury_fee_bps = 5000;
+
+        let report_with_fee = simulate(&config, &[position()], &path).unwrap();
+
+        assert_eq!(report.treasury_fees, 0);
+        assert!(report_with_fee.treasury_fees > 0);
+        assert_eq!(report_with_fee.collateral_seized, report.collateral_seized);
+        assert!(report_with_fee.liquidator_profit < report.liquidator_profit);
+    }
+
+    #[test]
+    fn reports_bad_debt_when_vault_cannot_cover_bonus() {
+        // $50 of collateral against $60 of debt
+        let report = run("-50");
+
+        assert_eq!(report.liquidations, 0);
+        assert_eq!(report.underwater_positions, 1);
+        assert_eq!(report.bad_debt, 10_000_000);
+    }
+
+    #[test]
+    fn gradual_declines_liquidate_before_going_underwater() {
+        let report = run("-50:50");
+
+        assert!(report.liquidations > 0);
+        assert_eq!(report.bad_debt, 0);
+    }
+}
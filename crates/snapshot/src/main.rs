@@ -0,0 +1,334 @@
+//! Dumps stablecoin accounts to a snapshot directory and loads snapshots into local validators.
+//!
+//! A snapshot directory contains:
+//! - `accounts/<ADDRESS>.json`, raw accounts in the `solana account --output json` format,
+//!   including position vaults and stake accounts
+//! - `snapshot.json`, decoded config, positions, supply and price
+//! - `positions.csv`, one row per position
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use serde_json::json;
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use solana_commitment_config::CommitmentConfig;
+use stablecoin::{Position, Wad};
+use stablecoin_client::{
+    health_factor_bps, mint_pda, stake_pda, stub_quote_fixture, vault_pda, AccountFixture,
+    PositionSnapshot, ProtocolState,
+};
+
+/// Maximum accounts per `getMultipleAccounts` request.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    #[arg(
+        long,
+        env = "RPC_URL",
+        default_value = "http://127.0.0.1:8899",
+        global = true
+    )]
+    rpc_url: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Dumps program accounts, position vaults and stake accounts, the mint and optionally the
+    /// oracle quote.
+    Export {
+        /// Snapshot directory, created if missing.
+        #[arg(long)]
+        out: PathBuf,
+        /// Switchboard SOL/USD quote account to include.
+        #[arg(long)]
+        oracle_quote: Option<Pubkey>,
+    },
+    /// Writes snapshot accounts into a running Surfpool instance with `surfnet_setAccount`.
+    Import {
+        /// Snapshot directory.
+        #[arg(long)]
+        dir: PathBuf,
+    },
+    /// Prints `solana-test-validator` arguments that preload snapshot accounts.
+    ValidatorArgs {
+        /// Snapshot directory.
+        #[arg(long)]
+        dir: PathBuf,
+    },
+    /// Writes an unsigned SOL/USD quote account, accepted only by the `no-staleness-check` build.
+    StubQuote {
+        /// SOL/USD price in USD.
+        #[arg(long)]
+        price: f64,
+        /// Slot the quote is recorded at.
+        #[arg(long, default_value_t = 0)]
+        slot: u64,
+        /// Fixture path.
+        #[arg(long)]
+        out: PathBuf,
+    },
+}
+
+#[derive(Serialize)]
+struct ConfigSummary {
+    version: u8,
+    authority: String,
+    min_loan_to_value_bps: u16,
+    liquidation_threshold_bps: u16,
+    liquidation_bonus_bps: u16,
+    critical_collateral_ratio_bps: u16,
+    total_collateral: u64,
+    total_debt: u64,
+}
+
+#[derive(Serialize)]
+struct Manifest {
+    /// Slot at which the export started. Accounts are fetched in several requests and may be
+    /// from slightly later slots.
+    slot: u64,
+    program_id: String,
+    /// SOL/USD price as a decimal string, if the oracle quote was exported.
+    price: Option<String>,
+    supply: u64,
+    config: ConfigSummary,
+    positions: Vec<PositionSnapshot>,
+}
+
+#[derive(Serialize)]
+struct PositionRow<'a> {
+    address: &'a str,
+    depositor: &'a str,
+    collateral: u64,
+    amount_minted: u64,
+    auction_ends_at: i64,
+    health_factor_bps: Option<u64>,
+}
+
+fn accounts_dir(dir: &Path) -> PathBuf {
+    dir.join("accounts")
+}
+
+fn account_paths(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = fs::read_dir(accounts_dir(dir))
+        .with_context(|| format!("reading {dir:?}"))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "json")
+    });
+    paths.sort();
+
+    Ok(paths)
+}
+
+async fn fetch_accounts(rpc: &RpcClient, pubkeys: &[Pubkey]) -> Result<Vec<AccountFixture>> {
+    let mut fixtures = vec![];
+
+    for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        for (pubkey, account) in chunk.iter().zip(rpc.get_multiple_accounts(chunk).await?) {
+            if let Some(account) = account {
+                fixtures.push(AccountFixture::new(
+                    pubkey,
+                    account.lamports,
+                    &account.data,
+                    &account.owner,
+                ));
+            }
+        }
+    }
+
+    Ok(fixtures)
+}
+
+async fn export(rpc: &RpcClient, out: &Path, oracle_quote: Option<Pubkey>) -> Result<()> {
+    let slot = rpc.get_slot().await?;
+    let program_accounts = rpc.get_program_accounts(&stablecoin::ID).await?;
+    let vaults = program_accounts
+        .iter()
+        .filter(|(_, account)| account.data.starts_with(Position::DISCRIMINATOR))
+        .flat_map(|(address, _)| [vault_pda(address), stake_pda(address)])
+        .collect::<Vec<_>>();
+
+    let mut fixtures = program_accounts
+        .iter()
+        .map(|(pubkey, account)| {
+            AccountFixture::new(pubkey, account.lamports, &account.data, &account.owner)
+        })
+        .collect::<Vec<_>>();
+
+    fixtures.extend(fetch_accounts(rpc, &vaults).await?);
+    fixtures.extend(
+        fetch_accounts(
+            rpc,
+            &[mint_pda()]
+                .into_iter()
+                .chain(oracle_quote)
+                .collect::<Vec<_>>(),
+        )
+        .await?,
+    );
+
+    fs::create_dir_all(accounts_dir(out))?;
+
+    for fixture in &fixtures {
+        fs::write(
+            accounts_dir(out).join(format!("{}.json", fixture.pubkey)),
+            serde_json::to_string_pretty(fixture)?,
+        )?;
+    }
+
+    // raw accounts are kept even if decoding fails, so undecodable state can still be reproduced
+    let state = ProtocolState::from_fixtures(&fixtures, oracle_quote.as_ref())
+        .context("decoding exported accounts")?;
+
+    write_positions_csv(&out.join("positions.csv"), &state.positions, state.price)?;
+
+    let config = &state.config;
+    let manifest = Manifest {
+        slot,
+        program_id: stablecoin::ID.to_string(),
+        price: state.price.map(|price| price.to_string()),
+        supply: state.supply,
+        config: ConfigSummary {
+            version: config.version,
+            authority: config.authority.to_string(),
+            min_loan_to_value_bps: config.min_loan_to_value_bps,
+            liquidation_threshold_bps: config.liquidation_threshold_bps,
+            liquidation_bonus_bps: config.liquidation_bonus_bps,
+            critical_collateral_ratio_bps: config.critical_collateral_ratio_bps,
+            total_collateral: config.total_collateral,
+            total_debt: config.total_debt,
+        },
+        positions: state.positions,
+    };
+
+    fs::write(
+        out.join("snapshot.json"),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+
+    println!(
+        "exported {} accounts and {} positions at slot {slot} to {out:?}",
+        fixtures.len(),
+        manifest.positions.len()
+    );
+
+    Ok(())
+}
+
+fn write_positions_csv(
+    path: &Path,
+    positions: &[PositionSnapshot],
+    price: Option<Wad>,
+) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+
+    for position in positions {
+        let health_factor_bps = price
+            .filter(|_| position.amount_minted > 0)
+            .map(|price| position.health_factor(price))
+            .transpose()?
+            .map(health_factor_bps);
+
+        writer.serialize(PositionRow {
+            address: &position.address,
+            depositor: &position.depositor,
+            collateral: position.collateral,
+            amount_minted: position.amount_minted,
+            auction_ends_at: position.auction_ends_at,
+            health_factor_bps,
+        })?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+async fn import(rpc: &RpcClient, dir: &Path) -> Result<()> {
+    let paths = account_paths(dir)?;
+
+    for path in &paths {
+        let fixture = AccountFixture::load(path)?;
+        let data = fixture
+            .data()?
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        rpc.send::<serde_json::Value>(
+            RpcRequest::Custom {
+                method: "surfnet_setAccount",
+            },
+            json!([
+                fixture.pubkey,
+                {
+                    "data": data,
+                    "executable": fixture.account.executable,
+                    "lamports": fixture.account.lamports,
+                    "owner": fixture.account.owner,
+                    "rentEpoch": fixture.account.rent_epoch,
+                }
+            ]),
+        )
+        .await
+        .with_context(|| format!("setting account {}", fixture.pubkey))?;
+    }
+
+    println!("imported {} accounts from {dir:?}", paths.len());
+
+    Ok(())
+}
+
+fn validator_args(dir: &Path) -> Result<()> {
+    let args = account_paths(dir)?
+        .iter()
+        .map(|path| {
+            let fixture = AccountFixture::load(path)?;
+
+            Ok(format!("--account {} {}", fixture.pubkey, path.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    println!("{}", args.join(" "));
+
+    Ok(())
+}
+
+fn stub_quote(price: f64, slot: u64, out: &Path) -> Result<()> {
+    let fixture = stub_quote_fixture(price, slot)?;
+
+    fs::write(out, serde_json::to_string_pretty(&fixture)? + "\n")?;
+
+    println!(
+        "wrote oracle quote {} at ${price} to {out:?}",
+        fixture.pubkey
+    );
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let Args { rpc_url, command } = Args::parse();
+    let rpc = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+
+    match command {
+        Command::Export { out, oracle_quote } => export(&rpc, &out, oracle_quote).await,
+        Command::Import { dir } => import(&rpc, &dir).await,
+        Command::ValidatorArgs { dir } => validator_args(&dir),
+        Command::StubQuote { price, slot, out } => stub_quote(price, slot, &out),
+    }
+}
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
+#[constant]
+pub const PRICE_DECIMALS: u8 = 18;
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{PriceFeed, PRICE_FEED_SEED};
+
+#[derive(Accounts)]
+#[instruction(feed_id: [u8; 32])]
+pub struct InitializePriceFeed<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = PriceFeed::DISCRIMINATOR.len() + PriceFeed::INIT_SPACE,
+        seeds = [PRICE_FEED_SEED, feed_id.as_ref()],
+        bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializePriceFeed<'_> {
+    pub fn handler(ctx: Context<InitializePriceFeed>, feed_id: [u8; 32]) -> Result<()> {
+        let InitializePriceFeed {
+            authority,
+            price_feed,
+            ..
+        } = ctx.accounts;
+
+        price_feed.set_inner(PriceFeed {
+            authority: authority.key(),
+            feed_id,
+            price: 0,
+            confidence: 0,
+            timestamp: 0,
+            slot: 0,
+            bump: ctx.bumps.price_feed,
+        });
+
+        Ok(())
+    }
+}
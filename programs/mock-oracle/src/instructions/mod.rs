@@ -0,0 +1,5 @@
+pub mod initialize_price_feed;
+pub use initialize_price_feed::*;
+
+pub mod set_price;
+pub use set_price::*;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{PriceFeed, PRICE_FEED_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPriceArgs {
+    pub price: i128,
+    pub confidence: u128,
+    /// Defaults to the current unix timestamp.
+    pub timestamp: Option<i64>,
+    /// Defaults to the current slot. Set an older slot to make the price stale.
+    pub slot: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED, price_feed.feed_id.as_ref()],
+        bump = price_feed.bump,
+        has_one = authority,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+impl SetPrice<'_> {
+    pub fn handler(ctx: Context<SetPrice>, args: SetPriceArgs) -> Result<()> {
+        let SetPriceArgs {
+            price,
+            confidence,
+            timestamp,
+            slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        let price_feed = &mut ctx.accounts.price_feed;
+
+        price_feed.price = price;
+        price_feed.confidence = confidence;
+        price_feed.timestamp = timestamp.unwrap_or(clock.unix_timestamp);
+        price_feed.slot = slot.unwrap_or(clock.slot);
+
+        Ok(())
+    }
+}
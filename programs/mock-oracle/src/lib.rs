@@ -0,0 +1,27 @@
+pub mod constants;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+
+pub use constants::*;
+pub use instructions::*;
+pub use state::*;
+
+declare_id!("3EPmSyTpsrYJmW2xupCD3MNfKuNFzHCmmFnrGg2f4fTf");
+
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    pub fn initialize_price_feed(
+        ctx: Context<InitializePriceFeed>,
+        feed_id: [u8; 32],
+    ) -> Result<()> {
+        InitializePriceFeed::handler(ctx, feed_id)
+    }
+
+    pub fn set_price(ctx: Context<SetPrice>, args: SetPriceArgs) -> Result<()> {
+        SetPrice::handler(ctx, args)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod price_feed;
+pub use price_feed::*;
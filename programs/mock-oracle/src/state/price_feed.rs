@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Price feed whose value is set directly by its authority, standing in for a Switchboard quote.
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    /// Address allowed to set the price.
+    pub authority: Pubkey,
+    /// Switchboard feed id this feed stands in for.
+    pub feed_id: [u8; 32],
+    /// Price with 18 decimals. Zero and negative prices are accepted so invalid quotes can be
+    /// tested.
+    pub price: i128,
+    /// Confidence interval with 18 decimals.
+    pub confidence: u128,
+    /// Unix timestamp the price was observed at.
+    pub timestamp: i64,
+    /// Slot the price was observed at.
+    pub slot: u64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
@@ -9,6 +9,28 @@ pub const VAULT_SEED: &[u8] = b"vault";
 #[constant]
 pub const MINT_SEED: &[u8] = b"mint";
 #[constant]
+pub const AUCTION_SEED: &[u8] = b"auction";
+#[constant]
+pub const STABILITY_POOL_SEED: &[u8] = b"stability_pool";
+#[constant]
+pub const STABILITY_DEPOSIT_SEED: &[u8] = b"stability_deposit";
+#[constant]
+pub const PSM_SEED: &[u8] = b"psm";
+#[constant]
+pub const BLOCKLIST_SEED: &[u8] = b"blocklist";
+#[constant]
+pub const STAKE_SEED: &[u8] = b"stake";
+#[constant]
+pub const SAVINGS_SEED: &[u8] = b"savings";
+#[constant]
+pub const SAVINGS_DEPOSIT_SEED: &[u8] = b"savings_deposit";
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+#[constant]
+pub const CONFIG_VERSION: u8 = 1;
+#[constant]
+pub const POSITION_VERSION: u8 = 1;
+#[constant]
 pub const MAX_BASIS_POINTS: u16 = 10000;
 #[constant]
 pub const ORACLE_MAX_AGE: u16 = 100;
@@ -20,3 +42,13 @@ pub const SOL_USD_FEED_ID: [u8; 32] = [
 ];
 #[constant]
 pub const MINT_DECIMALS: u8 = 6;
+#[constant]
+pub const REDEMPTION_BASE_RATE_HALF_LIFE: i64 = 43200;
+#[constant]
+pub const SECONDS_PER_YEAR: i64 = 31_536_000;
+#[constant]
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+#[constant]
+pub const STABILITY_POOL_PRECISION: u128 = 1_000_000_000_000_000_000;
+#[constant]
+pub const STABILITY_POOL_SCALE_FACTOR: u128 = 1_000_000_000;
@@ -28,4 +28,76 @@ pub enum StablecoinError {
     InvalidLtvConfiguration,
     #[msg("Position does not have enough mint amount to burn")]
     InsufficientAmountMinted,
+    #[msg("Position collateral is being auctioned")]
+    PositionInAuction,
+    #[msg("Auctions are disabled")]
+    AuctionsDisabled,
+    #[msg("Auction is still in progress")]
+    AuctionInProgress,
+    #[msg("Auction has expired")]
+    AuctionExpired,
+    #[msg("Stability pool does not have enough deposits to absorb debt")]
+    InsufficientStabilityPoolDeposits,
+    #[msg("Stability deposit amount must be greater than 0")]
+    InvalidStabilityDepositAmount,
+    #[msg("Positions must be sorted by ascending health factor")]
+    PositionsNotSortedByHealthFactor,
+    #[msg("No debt could be redeemed from the given positions")]
+    NothingToRedeem,
+    #[msg("Swap amount must be greater than 0")]
+    InvalidSwapAmount,
+    #[msg("PSM debt ceiling exceeded")]
+    PsmDebtCeilingExceeded,
+    #[msg("PSM does not have enough reserves")]
+    InsufficientPsmReserves,
+    #[msg("Flash mint amount must be greater than 0 and within limit")]
+    InvalidFlashMintAmount,
+    #[msg("Flash mint must be a top-level instruction")]
+    FlashMintCpiNotAllowed,
+    #[msg("Flash mint is not repaid later in the transaction")]
+    MissingFlashRepay,
+    #[msg("Flash mint receiver program is invalid")]
+    InvalidFlashMintReceiver,
+    #[msg("Mint extensions require Token-2022 program")]
+    MintExtensionsRequireToken2022,
+    #[msg("Address is blocklisted")]
+    AddressBlocklisted,
+    #[msg("Deleveraging is disabled")]
+    DeleverageDisabled,
+    #[msg("Swap adapter does not match config")]
+    InvalidSwapAdapter,
+    #[msg("Swap proceeds are below oracle price less slippage")]
+    DeleverageSlippageExceeded,
+    #[msg("Health factor cannot decrease")]
+    HealthFactorDecreased,
+    #[msg("Account is already on the current layout version")]
+    AccountAlreadyMigrated,
+    #[msg("System collateral ratio cannot fall below critical ratio")]
+    SystemCollateralRatioBelowCritical,
+    #[msg("Critical collateral ratio must be greater than liquidation threshold")]
+    InvalidCriticalCollateralRatio,
+    #[msg("Oracle price is stale")]
+    StalePrice,
+    #[msg("Position collateral is already staked")]
+    CollateralAlreadyStaked,
+    #[msg("Position collateral is not staked")]
+    CollateralNotStaked,
+    #[msg("Savings amount must be greater than 0")]
+    InvalidSavingsAmount,
+    #[msg("Treasury withdrawal exceeds limit for this epoch")]
+    TreasuryWithdrawalLimitExceeded,
+    #[msg("Treasury withdrawal amount must be greater than 0")]
+    InvalidTreasuryWithdrawalAmount,
+    #[msg("Oracle price is below the minimum price set by the signer")]
+    PriceBelowMinimum,
+    #[msg("Oracle price is above the maximum price set by the signer")]
+    PriceAboveMaximum,
+    #[msg("Collateral paid out is below the minimum set by the signer")]
+    CollateralOutBelowMinimum,
+    #[msg("Position collateral is worth less than its debt")]
+    PositionUnderwater,
+    #[msg("Stake accounts are required to seize staked collateral")]
+    MissingStakeAccounts,
+    #[msg("Swap adapter cannot be the stablecoin program")]
+    InvalidSwapAdapterProgram,
 }
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the liquidation bonus share is paid to the treasury.
+#[event]
+pub struct TreasuryFeeCollected {
+    /// Position that was liquidated.
+    pub position: Pubkey,
+    /// Lamports paid to the treasury.
+    pub lamports: u64,
+}
+
+/// Emitted when the authority withdraws from the treasury.
+#[event]
+pub struct TreasuryWithdrawn {
+    /// Address receiving the withdrawal.
+    pub recipient: Pubkey,
+    /// Lamports withdrawn.
+    pub lamports: u64,
+    /// Stablecoin withdrawn, in base units.
+    pub amount: u64,
+    /// Epoch of the withdrawal.
+    pub epoch: u64,
+    /// Lamports withdrawn so far in the epoch.
+    pub sol_withdrawn: u64,
+    /// Stablecoin withdrawn so far in the epoch, in base units.
+    pub token_withdrawn: u64,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{BlocklistEntry, Config, BLOCKLIST_SEED, CONFIG_SEED};
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddToBlocklist<'info> {
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = compliance_authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = compliance_authority,
+        space = BlocklistEntry::DISCRIMINATOR.len() + BlocklistEntry::INIT_SPACE,
+        seeds = [BLOCKLIST_SEED, address.as_ref()],
+        bump,
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+impl AddToBlocklist<'_> {
+    pub fn handler(ctx: Context<AddToBlocklist>, address: Pubkey) -> Result<()> {
+        ctx.accounts.blocklist_entry.set_inner(BlocklistEntry {
+            address,
+            bump: ctx.bumps.blocklist_entry,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke},
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::spl_token::native_mint,
+    token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface},
+};
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_health_factor, error::StablecoinError, get_oracle_price, oracle_quote_address,
+    validate_price, vault_signer, Config, OracleQuoteAccount, Position, SafeMath, SafeMathAssign,
+    Wad, CONFIG_SEED, MINT_DECIMALS, MINT_SEED, POSITION_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct Deleverage<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Swap adapter selling SOL for stablecoin, accounts passed as remaining accounts
+    #[account(
+        executable,
+        address = config.swap_adapter @ StablecoinError::InvalidSwapAdapter,
+    )]
+    pub swap_adapter: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> Deleverage<'info> {
+    /// Sells `collateral_amount` of vault SOL through the swap adapter and burns the stablecoin
+    /// received to repay debt. Proceeds must be within slippage of oracle price, no bonus is paid.
+    ///
+    /// Adapter is invoked with depositor as signer and must deliver stablecoin to depositor token
+    /// account, e.g. by routing SOL through a DEX. The adapter cannot be this program, as a nested
+    /// call would act on the position and config while they are held here.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, Deleverage<'info>>,
+        collateral_amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        require_gt!(
+            collateral_amount,
+            0,
+            StablecoinError::InvalidCollateralAmount
+        );
+
+        let Deleverage {
+            depositor,
+            config,
+            position,
+            oracle_queue,
+            oracle_quote,
+            vault,
+            mint,
+            depositor_token_account,
+            swap_adapter,
+            system_program,
+            token_program,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            ..
+        } = ctx.accounts;
+
+        require_keys_neq!(
+            config.swap_adapter,
+            Pubkey::default(),
+            StablecoinError::DeleverageDisabled
+        );
+        require_keys_neq!(
+            swap_adapter.key(),
+            crate::ID,
+            StablecoinError::InvalidSwapAdapterProgram
+        );
+        require!(
+            !position.is_in_auction(Clock::get()?.unix_timestamp),
+            StablecoinError::PositionInAuction
+        );
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let pre_health_factor = calculate_health_factor(
+            position.collateral(vault.lamports())?,
+            position.amount_minted,
+            price,
+        )?;
+
+        let lamport_balance = vault.lamports().safe_sub(collateral_amount)?;
+        let min_rent = Rent::get()?.minimum_balance(vault.data_len());
+
+        require_gte!(
+            lamport_balance,
+            min_rent,
+            StablecoinError::RentBelowMinimumAfterWithdrawal
+        );
+
+        // rounded up against depositor
+        let min_amount_out = Wad::from_amount(collateral_amount, native_mint::DECIMALS)?
+            .mul_ceil(price)?
+            .mul_ceil(Wad::ONE.safe_sub(Wad::from_bps(config.deleverage_slippage_bps))?)?
+            .to_amount_ceil(MINT_DECIMALS)?;
+
+        let position_key = position.key();
+        let vault_bump = position.vault_bump;
+        let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: depositor.to_account_info(),
+                },
+                &[vault_signer],
+            ),
+            collateral_amount,
+        )?;
+
+        let pre_token_balance = depositor_token_account.amount;
+
+        let swap_ix = Instruction {
+            program_id: swap_adapter.key(),
+            accounts: ctx
+                .remaining_accounts
+                .iter()
+                .map(|account| {
+                    if account.is_writable {
+                        AccountMeta::new(account.key(), account.is_signer)
+                    } else {
+                        AccountMeta::new_readonly(account.key(), account.is_signer)
+                    }
+                })
+                .collect(),
+            data,
+        };
+
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(swap_adapter.to_account_info());
+
+        invoke(&swap_ix, &account_infos)?;
+
+        depositor_token_account.reload()?;
+
+        let amount_out = depositor_token_account
+            .amount
+            .saturating_sub(pre_token_balance);
+
+        require_gte!(
+            amount_out,
+            min_amount_out,
+            StablecoinError::DeleverageSlippageExceeded
+        );
+
+        // proceeds above outstanding debt are left with depositor
+        let amount_to_burn = amount_out.min(position.amount_minted);
+
+        position.amount_minted.safe_sub_assign(amount_to_burn)?;
+        config.decrease_totals(collateral_amount, amount_to_burn)?;
+
+        let health_factor = calculate_health_factor(
+            position.collateral(lamport_balance)?,
+            position.amount_minted,
+            price,
+        )?;
+
+        require_gte!(
+            health_factor,
+            pre_health_factor,
+            StablecoinError::HealthFactorDecreased
+        );
+
+        if amount_to_burn > 0 {
+            burn_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    BurnChecked {
+                        authority: depositor.to_account_info(),
+                        from: depositor_token_account.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                ),
+                amount_to_burn,
+                mint.decimals,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -6,12 +6,14 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
 };
-use switchboard_on_demand::{default_queue, get_slot, SwitchboardQuote, SwitchboardQuoteExt};
+use switchboard_on_demand::default_queue;
 
 use crate::{
-    bps_to_decimal, calculate_health_factor, error::StablecoinError, get_oracle_quote,
-    get_price_from_quote, mint_signer, validate_above_min_health_factor, validate_price, Config,
-    Position, SafeMath, SafeMathAssign, CONFIG_SEED, MINT_SEED, POSITION_SEED, VAULT_SEED,
+    calculate_health_factor, error::StablecoinError, get_oracle_price, mint_signer,
+    oracle_quote_address, validate_above_min_health_factor, validate_not_blocklisted,
+    validate_price, validate_price_bounds, validate_system_collateral_ratio, Config,
+    OracleQuoteAccount, Position, SafeMath, SafeMathAssign, Wad, BLOCKLIST_SEED, CONFIG_SEED,
+    MINT_SEED, POSITION_SEED, POSITION_VERSION, VAULT_SEED,
 };
 
 #[derive(Accounts)]
@@ -19,10 +21,17 @@ pub struct DepositCollateral<'info> {
     #[account(mut)]
     pub depositor: Signer<'info>,
     #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump = config.bump,
     )]
     pub config: Account<'info, Config>,
+    /// CHECK: Blocklist entry of depositor, must not exist
+    #[account(
+        seeds = [BLOCKLIST_SEED, depositor.key().as_ref()],
+        bump,
+    )]
+    pub blocklist_entry: UncheckedAccount<'info>,
     #[account(
         init_if_needed,
         payer = depositor,
@@ -37,9 +46,9 @@ pub struct DepositCollateral<'info> {
     )]
     pub oracle_queue: UncheckedAccount<'info>,
     #[account(
-        address = oracle_quote.canonical_key(&default_queue())
+        address = oracle_quote_address(&oracle_quote)
     )]
-    pub oracle_quote: Box<Account<'info, SwitchboardQuote>>,
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
     #[account(
         mut,
         seeds = [VAULT_SEED, position.key().as_ref()],
@@ -74,10 +83,14 @@ pub struct DepositCollateral<'info> {
 }
 
 impl<'info> DepositCollateral<'info> {
+    /// Deposits `collateral_amount` and mints `amount_to_mint`, reverting if the oracle price is
+    /// outside the optional `min_price` and `max_price` bounds, scaled by `WAD`.
     pub fn handler(
         ctx: Context<DepositCollateral>,
         collateral_amount: u64,
         amount_to_mint: u64,
+        min_price: Option<u128>,
+        max_price: Option<u128>,
     ) -> Result<()> {
         require_gt!(
             collateral_amount,
@@ -98,41 +111,73 @@ impl<'info> DepositCollateral<'info> {
             instructions_sysvar,
             slot_hashes_sysvar,
             clock,
+            blocklist_entry,
             ..
         } = ctx.accounts;
 
+        validate_not_blocklisted(blocklist_entry)?;
+
         if position.bump == 0 {
             **position = Position {
+                version: POSITION_VERSION,
                 depositor: depositor.key(),
                 amount_minted: 0,
+                auction_ends_at: 0,
                 bump: ctx.bumps.position,
                 vault_bump: ctx.bumps.vault,
+                staked_collateral: 0,
+                stake_bump: 0,
+                reserved: [0; 55],
             }
         }
 
-        let lamport_balance = vault.lamports().safe_add(collateral_amount)?;
-        position.amount_minted.safe_add_assign(amount_to_mint)?;
+        let now = Clock::get()?.unix_timestamp;
 
-        let oracle_quote_data = oracle_quote.to_account_data().unwrap();
+        require!(
+            !position.is_in_auction(now),
+            StablecoinError::PositionInAuction
+        );
 
-        let quote = get_oracle_quote(
-            oracle_queue.to_account_info(),
-            slot_hashes_sysvar.to_account_info(),
-            instructions_sysvar.to_account_info(),
-            get_slot(&clock.to_account_info()),
-            &oracle_quote_data.as_slice(),
-        )?;
+        // origination fee is owed as debt and counted as fee income for savings
+        let origination_fee = config.origination_fee(amount_to_mint, now)?;
+        let debt_increase = amount_to_mint.safe_add(origination_fee)?;
 
-        let price = get_price_from_quote(quote)?;
+        let lamport_balance = vault.lamports().safe_add(collateral_amount)?;
+        position.amount_minted.safe_add_assign(debt_increase)?;
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
 
         validate_price(price)?;
+        validate_price_bounds(price, min_price, max_price)?;
 
-        let health_factor =
-            calculate_health_factor(lamport_balance, position.amount_minted, price)?;
+        // new debt must meet the critical ratio in recovery mode, top-ups are always allowed
+        let recovery_mode = amount_to_mint > 0 && config.is_recovery_mode(price)?;
+
+        let health_factor = calculate_health_factor(
+            position.collateral(lamport_balance)?,
+            position.amount_minted,
+            price,
+        )?;
 
         validate_above_min_health_factor(
             health_factor,
-            bps_to_decimal(config.min_loan_to_value_bps)?,
+            Wad::from_bps(config.effective_min_loan_to_value_bps(recovery_mode)),
+        )?;
+
+        let system_collateral_ratio = config.system_collateral_ratio(price)?;
+        config.increase_totals(collateral_amount, debt_increase)?;
+        config.surplus.safe_add_assign(origination_fee)?;
+
+        validate_system_collateral_ratio(
+            system_collateral_ratio,
+            config.system_collateral_ratio(price)?,
+            Wad::from_bps(config.critical_collateral_ratio_bps),
         )?;
 
         transfer(
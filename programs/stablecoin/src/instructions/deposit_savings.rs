@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::StablecoinError, mint_signer, Config, SafeMath, SafeMathAssign, Savings, SavingsDeposit,
+    CONFIG_SEED, MINT_SEED, SAVINGS_DEPOSIT_SEED, SAVINGS_SEED,
+};
+
+#[derive(Accounts)]
+pub struct DepositSavings<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [SAVINGS_SEED],
+        bump = savings.bump,
+    )]
+    pub savings: Account<'info, Savings>,
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = SavingsDeposit::DISCRIMINATOR.len() + SavingsDeposit::INIT_SPACE,
+        seeds = [SAVINGS_DEPOSIT_SEED, depositor.key().as_ref()],
+        bump,
+    )]
+    pub savings_deposit: Account<'info, SavingsDeposit>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = savings,
+        associated_token::token_program = token_program
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositSavings<'info> {
+    /// Drips accrued interest, then deposits `amount` in exchange for shares at the new share value.
+    pub fn handler(ctx: Context<DepositSavings>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, StablecoinError::InvalidSavingsAmount);
+
+        let DepositSavings {
+            depositor,
+            config,
+            savings,
+            savings_deposit,
+            mint,
+            depositor_token_account,
+            savings_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        if savings_deposit.bump == 0 {
+            savings_deposit.depositor = depositor.key();
+            savings_deposit.bump = ctx.bumps.savings_deposit;
+        }
+
+        let interest = savings.drip(config, Clock::get()?.unix_timestamp)?;
+
+        if interest > 0 {
+            let mint_bump = config.mint_bump;
+            let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+            mint_to(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    MintTo {
+                        authority: mint.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: savings_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[mint_signer]),
+                interest,
+            )?;
+        }
+
+        // mint may charge transfer fees, so only the amount received is deposited
+        let pre_savings_balance = savings_token_account.amount.safe_add(interest)?;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: depositor.to_account_info(),
+                    from: depositor_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: savings_token_account.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        savings_token_account.reload()?;
+
+        let amount_received = savings_token_account.amount.safe_sub(pre_savings_balance)?;
+        let shares = savings.amount_to_shares(amount_received)?;
+
+        require_gt!(shares, 0, StablecoinError::InvalidSavingsAmount);
+
+        savings_deposit.shares.safe_add_assign(shares)?;
+        savings.total_shares.safe_add_assign(shares)?;
+
+        Ok(())
+    }
+}
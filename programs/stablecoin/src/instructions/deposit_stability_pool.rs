@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::StablecoinError, Config, SafeMath, SafeMathAssign, StabilityDeposit, StabilityPool,
+    CONFIG_SEED, MINT_SEED, STABILITY_DEPOSIT_SEED, STABILITY_POOL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct DepositStabilityPool<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [STABILITY_POOL_SEED],
+        bump = stability_pool.bump,
+    )]
+    pub stability_pool: Account<'info, StabilityPool>,
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = StabilityDeposit::DISCRIMINATOR.len() + StabilityDeposit::INIT_SPACE,
+        seeds = [STABILITY_DEPOSIT_SEED, depositor.key().as_ref()],
+        bump,
+    )]
+    pub stability_deposit: Account<'info, StabilityDeposit>,
+    #[account(
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stability_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositStabilityPool<'info> {
+    pub fn handler(ctx: Context<DepositStabilityPool>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, StablecoinError::InvalidStabilityDepositAmount);
+
+        let DepositStabilityPool {
+            depositor,
+            stability_pool,
+            stability_deposit,
+            mint,
+            depositor_token_account,
+            pool_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        if stability_deposit.bump == 0 {
+            stability_deposit.depositor = depositor.key();
+            stability_deposit.bump = ctx.bumps.stability_deposit;
+        }
+
+        // mint may charge transfer fees, so only the amount received is deposited
+        let pre_pool_balance = pool_token_account.amount;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: depositor.to_account_info(),
+                    from: depositor_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: pool_token_account.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        pool_token_account.reload()?;
+
+        let amount_received = pool_token_account.amount.safe_sub(pre_pool_balance)?;
+        let compounded_amount = stability_deposit.compounded_amount(stability_pool)?;
+        let collateral_gain = stability_deposit.collateral_gain(stability_pool)?;
+
+        stability_deposit.snapshot(stability_pool, compounded_amount.safe_add(amount_received)?);
+        stability_pool
+            .total_deposits
+            .safe_add_assign(amount_received)?;
+
+        if collateral_gain > 0 {
+            stability_pool.sub_lamports(collateral_gain)?;
+            depositor.add_lamports(collateral_gain)?;
+        }
+
+        Ok(())
+    }
+}
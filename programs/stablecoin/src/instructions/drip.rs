@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::{mint_signer, Config, Savings, CONFIG_SEED, MINT_SEED, SAVINGS_SEED};
+
+#[derive(Accounts)]
+pub struct Drip<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [SAVINGS_SEED],
+        bump = savings.bump,
+    )]
+    pub savings: Account<'info, Savings>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = savings,
+        associated_token::token_program = token_program
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Drip<'info> {
+    /// Accrues savings interest and mints it into the savings token account.
+    pub fn handler(ctx: Context<Drip>) -> Result<()> {
+        let Drip {
+            config,
+            savings,
+            mint,
+            savings_token_account,
+            token_program,
+        } = ctx.accounts;
+
+        let interest = savings.drip(config, Clock::get()?.unix_timestamp)?;
+
+        if interest > 0 {
+            let mint_bump = config.mint_bump;
+            let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+            mint_to(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    MintTo {
+                        authority: mint.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: savings_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[mint_signer]),
+                interest,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{get_stack_height, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT},
+        program::invoke,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+    Discriminator,
+};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::{
+    error::StablecoinError, instruction, mint_signer, validate_not_blocklisted, Config,
+    BLOCKLIST_SEED, CONFIG_SEED, MINT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct FlashMint<'info> {
+    pub borrower: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: Blocklist entry of borrower, must not exist
+    #[account(
+        seeds = [BLOCKLIST_SEED, borrower.key().as_ref()],
+        bump,
+    )]
+    pub blocklist_entry: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Blocklist entry of receiver token account owner, must not exist
+    #[account(
+        seeds = [BLOCKLIST_SEED, receiver_token_account.owner.as_ref()],
+        bump,
+    )]
+    pub receiver_blocklist_entry: UncheckedAccount<'info>,
+    /// CHECK: Program invoked with minted stablecoin, accounts passed as remaining accounts
+    #[account(executable)]
+    pub receiver_program: Option<UncheckedAccount<'info>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> FlashMint<'info> {
+    /// Mints `amount` to receiver, which must be repaid with fee by a later `flash_repay`.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, FlashMint<'info>>,
+        amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let FlashMint {
+            config,
+            mint,
+            receiver_token_account,
+            receiver_program,
+            token_program,
+            instructions_sysvar,
+            blocklist_entry,
+            receiver_blocklist_entry,
+            ..
+        } = ctx.accounts;
+
+        validate_not_blocklisted(blocklist_entry)?;
+        validate_not_blocklisted(receiver_blocklist_entry)?;
+
+        require!(
+            amount > 0 && amount <= config.max_flash_mint,
+            StablecoinError::InvalidFlashMintAmount
+        );
+        // prevents flash mints from being nested inside receiver programs
+        require_eq!(
+            get_stack_height(),
+            TRANSACTION_LEVEL_STACK_HEIGHT,
+            StablecoinError::FlashMintCpiNotAllowed
+        );
+
+        validate_flash_repay(&instructions_sysvar.to_account_info(), &mint.key(), amount)?;
+
+        let mint_bump = config.mint_bump;
+        let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+        mint_to(
+            CpiContext::new(
+                token_program.to_account_info(),
+                MintTo {
+                    authority: mint.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: receiver_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[mint_signer]),
+            amount,
+        )?;
+
+        if let Some(receiver_program) = receiver_program {
+            require_keys_neq!(
+                receiver_program.key(),
+                crate::ID,
+                StablecoinError::InvalidFlashMintReceiver
+            );
+
+            let receiver_ix = Instruction {
+                program_id: receiver_program.key(),
+                accounts: ctx
+                    .remaining_accounts
+                    .iter()
+                    .map(|account| {
+                        if account.is_writable {
+                            AccountMeta::new(account.key(), account.is_signer)
+                        } else {
+                            AccountMeta::new_readonly(account.key(), account.is_signer)
+                        }
+                    })
+                    .collect(),
+                data,
+            };
+
+            let mut account_infos = ctx.remaining_accounts.to_vec();
+            account_infos.push(receiver_program.to_account_info());
+
+            invoke(&receiver_ix, &account_infos)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the next stablecoin instruction after the current one repays `amount` of `mint`.
+fn validate_flash_repay(
+    instructions_sysvar: &AccountInfo,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let mut index = current_index + 1;
+
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        index += 1;
+
+        if ix.program_id != crate::ID {
+            continue;
+        }
+
+        let discriminator = instruction::FlashRepay::DISCRIMINATOR;
+
+        require!(
+            !ix.data.starts_with(instruction::FlashMint::DISCRIMINATOR),
+            StablecoinError::MissingFlashRepay
+        );
+
+        if !ix.data.starts_with(discriminator) {
+            continue;
+        }
+
+        let args = instruction::FlashRepay::try_from_slice(&ix.data[discriminator.len()..])?;
+        // mint account of `FlashRepay`
+        let repay_mint = ix.accounts.get(2).map(|meta| meta.pubkey);
+
+        require!(
+            args.amount == amount && repay_mint == Some(*mint),
+            StablecoinError::MissingFlashRepay
+        );
+
+        return Ok(());
+    }
+
+    err!(StablecoinError::MissingFlashRepay)
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface};
+
+use crate::{Config, SafeMath, SafeMathAssign, CONFIG_SEED, MINT_SEED};
+
+#[derive(Accounts)]
+pub struct FlashRepay<'info> {
+    pub repayer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = repayer,
+        token::token_program = token_program,
+    )]
+    pub repayer_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FlashRepay<'info> {
+    /// Burns flash minted `amount` along with flash mint fee.
+    pub fn handler(ctx: Context<FlashRepay>, amount: u64) -> Result<()> {
+        let FlashRepay {
+            repayer,
+            config,
+            mint,
+            repayer_token_account,
+            token_program,
+        } = ctx.accounts;
+
+        let fee = config.flash_mint_fee(amount)?;
+        let amount_to_burn = amount.safe_add(fee)?;
+
+        burn_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                BurnChecked {
+                    authority: repayer.to_account_info(),
+                    from: repayer_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            amount_to_burn,
+            mint.decimals,
+        )?;
+
+        // burned fee leaves supply that can be minted back as savings interest
+        config.surplus.safe_add_assign(fee)?;
+
+        Ok(())
+    }
+}
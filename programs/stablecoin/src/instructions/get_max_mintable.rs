@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_max_mintable, get_oracle_price, oracle_quote_address, validate_price, Config,
+    OracleQuoteAccount, Position, SafeMath, Wad, CONFIG_SEED, MAX_BASIS_POINTS, POSITION_SEED,
+    VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct GetMaxMintable<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [POSITION_SEED, position.depositor.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl GetMaxMintable<'_> {
+    /// Returns additional stablecoin the position can mint at oracle price, intended to be
+    /// simulated.
+    pub fn handler(ctx: Context<GetMaxMintable>) -> Result<u64> {
+        let GetMaxMintable {
+            config,
+            position,
+            oracle_queue,
+            oracle_quote,
+            vault,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+        } = ctx.accounts;
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let recovery_mode = config.is_recovery_mode(price)?;
+
+        let mut max_mintable = calculate_max_mintable(
+            position.collateral(vault.lamports())?,
+            position.amount_minted,
+            price,
+            Wad::from_bps(config.effective_min_loan_to_value_bps(recovery_mode)),
+        )?;
+
+        if config.critical_collateral_ratio_bps > 0 {
+            // new debt cannot take the system collateral ratio below critical ratio
+            let system_max_mintable = calculate_max_mintable(
+                config.total_collateral,
+                config.total_debt,
+                price,
+                Wad::from_bps(config.critical_collateral_ratio_bps),
+            )?;
+
+            max_mintable = max_mintable.min(system_max_mintable);
+        }
+
+        // minted amount and its origination fee must both fit in the new debt
+        let fee_bps = config.origination_fee_bps(Clock::get()?.unix_timestamp)?;
+
+        Ok((max_mintable as u128)
+            .safe_mul(MAX_BASIS_POINTS.into())?
+            .safe_div(MAX_BASIS_POINTS.safe_add(fee_bps)?.into())? as u64)
+    }
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_max_withdrawable, get_oracle_price, oracle_quote_address, validate_price, Config,
+    OracleQuoteAccount, Position, Wad, CONFIG_SEED, POSITION_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct GetMaxWithdrawable<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [POSITION_SEED, position.depositor.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl GetMaxWithdrawable<'_> {
+    /// Returns lamports that can be withdrawn from the position at oracle price, intended to be
+    /// simulated.
+    pub fn handler(ctx: Context<GetMaxWithdrawable>) -> Result<u64> {
+        let GetMaxWithdrawable {
+            config,
+            position,
+            oracle_queue,
+            oracle_quote,
+            vault,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+        } = ctx.accounts;
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let min_rent = Rent::get()?.minimum_balance(vault.data_len());
+
+        // staked collateral counts towards health but cannot be withdrawn until unstaked
+        let max_withdrawable = calculate_max_withdrawable(
+            position.collateral(vault.lamports())?,
+            position.amount_minted,
+            price,
+            Wad::from_bps(config.min_loan_to_value_bps),
+            min_rent,
+        )?
+        .min(vault.lamports().saturating_sub(min_rent));
+
+        if config.critical_collateral_ratio_bps == 0 {
+            return Ok(max_withdrawable);
+        }
+
+        // withdrawals cannot take the system collateral ratio below critical ratio
+        let system_max_withdrawable = calculate_max_withdrawable(
+            config.total_collateral,
+            config.total_debt,
+            price,
+            Wad::from_bps(config.critical_collateral_ratio_bps),
+            0,
+        )?;
+
+        Ok(max_withdrawable.min(system_max_withdrawable))
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_collateral_value, calculate_health_factor, get_oracle_price, oracle_quote_address,
+    validate_price, Config, OracleQuoteAccount, Position, Wad, CONFIG_SEED, POSITION_SEED,
+    VAULT_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PositionHealth {
+    /// Collateral held in vault and staked, in lamports.
+    pub collateral: u64,
+    /// Collateral value at oracle price, in stablecoin base units.
+    pub collateral_value: u64,
+    /// Stablecoin minted against position, in base units.
+    pub amount_minted: u64,
+    /// Health factor in basis points, `u64::MAX` if nothing is minted.
+    pub health_factor_bps: u64,
+    /// Whether position is below the liquidation threshold, raised in recovery mode.
+    pub is_liquidatable: bool,
+}
+
+#[derive(Accounts)]
+pub struct GetPositionHealth<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [POSITION_SEED, position.depositor.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl GetPositionHealth<'_> {
+    /// Returns health of position at oracle price, intended to be simulated.
+    pub fn handler(ctx: Context<GetPositionHealth>) -> Result<PositionHealth> {
+        let GetPositionHealth {
+            config,
+            position,
+            oracle_queue,
+            oracle_quote,
+            vault,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+        } = ctx.accounts;
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let collateral = position.collateral(vault.lamports())?;
+        let health_factor = calculate_health_factor(collateral, position.amount_minted, price)?;
+        let liquidation_threshold = Wad::from_bps(
+            config.effective_liquidation_threshold_bps(config.is_recovery_mode(price)?),
+        );
+        let health_factor_bps =
+            u64::try_from(health_factor.0 / Wad::from_bps(1).0).unwrap_or(u64::MAX);
+
+        Ok(PositionHealth {
+            collateral,
+            collateral_value: calculate_collateral_value(collateral, price)?,
+            amount_minted: position.amount_minted,
+            health_factor_bps,
+            is_liquidatable: health_factor < liquidation_threshold,
+        })
+    }
+}
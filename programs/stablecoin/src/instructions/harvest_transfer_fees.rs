@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    harvest_withheld_tokens_to_mint, withdraw_withheld_tokens_from_mint,
+    HarvestWithheldTokensToMint, Mint, TokenAccount, TokenInterface,
+    WithdrawWithheldTokensFromMint,
+};
+
+use crate::{mint_signer, Config, Treasury, CONFIG_SEED, MINT_SEED, TREASURY_SEED};
+
+#[derive(Accounts)]
+pub struct HarvestTransferFees<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> HarvestTransferFees<'info> {
+    /// Harvests withheld transfer fees from token accounts passed as remaining accounts into
+    /// mint, then withdraws all fees withheld in mint to the treasury.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, HarvestTransferFees<'info>>) -> Result<()> {
+        let HarvestTransferFees {
+            config,
+            mint,
+            treasury_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        if !ctx.remaining_accounts.is_empty() {
+            harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    HarvestWithheldTokensToMint {
+                        token_program_id: token_program.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                ),
+                ctx.remaining_accounts.to_vec(),
+            )?;
+        }
+
+        let mint_bump = config.mint_bump;
+        let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+        withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            WithdrawWithheldTokensFromMint {
+                token_program_id: token_program.to_account_info(),
+                mint: mint.to_account_info(),
+                destination: treasury_token_account.to_account_info(),
+                authority: mint.to_account_info(),
+            },
+            &[mint_signer],
+        ))?;
+
+        Ok(())
+    }
+}
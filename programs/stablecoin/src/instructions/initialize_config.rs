@@ -1,13 +1,45 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack};
+use anchor_spl::{
+    token_2022::{
+        self,
+        spl_token_2022::{extension::ExtensionType, state::Mint},
+    },
+    token_interface::{
+        initialize_mint2, metadata_pointer_initialize,
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+        transfer_fee_initialize, InitializeMint2, MetadataPointerInitialize, TokenInterface,
+        TokenMetadataInitialize, TransferFeeInitialize,
+    },
+};
 
-use crate::{validate_bps, validate_ltv, Config, CONFIG_SEED, MINT_DECIMALS, MINT_SEED};
+use crate::{
+    create_pda_account, error::StablecoinError, mint_signer, validate_bps, validate_ltv, Config,
+    CONFIG_SEED, CONFIG_VERSION, MINT_DECIMALS, MINT_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransferFeeArgs {
+    pub transfer_fee_bps: u16,
+    pub maximum_fee: u64,
+}
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeConfigArgs {
     pub liquidation_threshold_bps: u16,
     pub liquidation_bonus_bps: u16,
     pub min_loan_to_value_bps: u16,
+    /// Token-2022 only, stored in mint via metadata pointer extension.
+    pub metadata: Option<MintMetadataArgs>,
+    /// Token-2022 only, withheld fees are harvested with `harvest_transfer_fees`.
+    pub transfer_fee: Option<TransferFeeArgs>,
 }
 
 #[derive(Accounts)]
@@ -22,35 +54,172 @@ pub struct InitializeConfig<'info> {
         bump,
     )]
     pub config: Account<'info, Config>,
+    /// CHECK: Created in handler as mint extensions depend on args
     #[account(
-        init,
-        payer = authority,
+        mut,
         seeds = [MINT_SEED],
         bump,
-        mint::authority = mint,
-        mint::decimals = MINT_DECIMALS,
-        mint::freeze_authority = mint,
-        mint::token_program = token_program
     )]
-    pub mint: InterfaceAccount<'info, Mint>,
+    pub mint: UncheckedAccount<'info>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 impl InitializeConfig<'_> {
     pub fn handler(ctx: Context<InitializeConfig>, args: InitializeConfigArgs) -> Result<()> {
-        let InitializeConfig { authority, .. } = ctx.accounts;
+        let InitializeConfig {
+            authority,
+            mint,
+            token_program,
+            system_program,
+            ..
+        } = ctx.accounts;
 
         validate_bps(args.liquidation_bonus_bps)?;
         validate_ltv(args.min_loan_to_value_bps, args.liquidation_threshold_bps)?;
 
+        let is_token_2022 = token_program.key() == token_2022::ID;
+
+        require!(
+            is_token_2022 || (args.metadata.is_none() && args.transfer_fee.is_none()),
+            StablecoinError::MintExtensionsRequireToken2022
+        );
+
+        let mut extensions = vec![];
+        let mut metadata_len = 0;
+
+        if let Some(metadata) = &args.metadata {
+            extensions.push(ExtensionType::MetadataPointer);
+
+            // token metadata is reallocated into mint on initialization
+            metadata_len = TokenMetadata {
+                update_authority: OptionalNonZeroPubkey(mint.key()),
+                mint: mint.key(),
+                name: metadata.name.clone(),
+                symbol: metadata.symbol.clone(),
+                uri: metadata.uri.clone(),
+                additional_metadata: vec![],
+            }
+            .tlv_size_of()?;
+        }
+
+        if let Some(transfer_fee) = &args.transfer_fee {
+            validate_bps(transfer_fee.transfer_fee_bps)?;
+
+            extensions.push(ExtensionType::TransferFeeConfig);
+        }
+
+        let space = if is_token_2022 {
+            ExtensionType::try_calculate_account_len::<Mint>(&extensions)?
+        } else {
+            Mint::LEN
+        };
+
+        let mint_key = mint.key();
+        let mint_bump = ctx.bumps.mint;
+        let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+        // mint address may have been sent lamports to block a plain create_account
+        create_pda_account(
+            authority.to_account_info(),
+            mint.to_account_info(),
+            system_program.to_account_info(),
+            Rent::get()?.minimum_balance(space + metadata_len),
+            space,
+            &token_program.key(),
+            &[mint_signer],
+        )?;
+
+        if args.metadata.is_some() {
+            metadata_pointer_initialize(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    MetadataPointerInitialize {
+                        token_program_id: token_program.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                ),
+                Some(mint_key),
+                Some(mint_key),
+            )?;
+        }
+
+        if let Some(transfer_fee) = &args.transfer_fee {
+            transfer_fee_initialize(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferFeeInitialize {
+                        token_program_id: token_program.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                ),
+                Some(&mint_key),
+                Some(&mint_key),
+                transfer_fee.transfer_fee_bps,
+                transfer_fee.maximum_fee,
+            )?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: mint.to_account_info(),
+                },
+            ),
+            MINT_DECIMALS,
+            &mint_key,
+            Some(&mint_key),
+        )?;
+
+        if let Some(metadata) = args.metadata {
+            token_metadata_initialize(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TokenMetadataInitialize {
+                        program_id: token_program.to_account_info(),
+                        metadata: mint.to_account_info(),
+                        update_authority: mint.to_account_info(),
+                        mint_authority: mint.to_account_info(),
+                        mint: mint.to_account_info(),
+                    },
+                    &[mint_signer],
+                ),
+                metadata.name,
+                metadata.symbol,
+                metadata.uri,
+            )?;
+        }
+
         ctx.accounts.config.set_inner(Config {
+            version: CONFIG_VERSION,
             bump: ctx.bumps.config,
             mint_bump: ctx.bumps.mint,
             liquidation_threshold_bps: args.liquidation_threshold_bps,
             liquidation_bonus_bps: args.liquidation_bonus_bps,
             min_loan_to_value_bps: args.min_loan_to_value_bps,
             authority: authority.key(),
+            compliance_authority: authority.key(),
+            auction_duration: 0,
+            auction_premium_bps: 0,
+            auction_discount_bps: 0,
+            redemption_fee_bps: 0,
+            redemption_base_rate_bps: 0,
+            last_redemption_at: 0,
+            max_flash_mint: 0,
+            flash_mint_fee_bps: 0,
+            swap_adapter: Pubkey::default(),
+            deleverage_slippage_bps: 0,
+            critical_collateral_ratio_bps: 0,
+            total_collateral: 0,
+            total_debt: 0,
+            savings_rate_bps: 0,
+            surplus: 0,
+            treasury_fee_bps: 0,
+            treasury_sol_withdrawal_limit: 0,
+            treasury_token_withdrawal_limit: 0,
+            origination_fee_bps: 0,
+            reserved: [0; 80],
         });
 
         Ok(())
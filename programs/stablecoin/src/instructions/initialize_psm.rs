@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{validate_bps, Config, Psm, CONFIG_SEED, PSM_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializePsmArgs {
+    pub mint_fee_bps: u16,
+    pub redeem_fee_bps: u16,
+    pub debt_ceiling: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitializePsm<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = Psm::DISCRIMINATOR.len() + Psm::INIT_SPACE,
+        seeds = [PSM_SEED, stable_mint.key().as_ref()],
+        bump,
+    )]
+    pub psm: Account<'info, Psm>,
+    #[account(
+        mint::token_program = stable_token_program,
+    )]
+    pub stable_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = stable_mint,
+        associated_token::authority = psm,
+        associated_token::token_program = stable_token_program
+    )]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub stable_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl InitializePsm<'_> {
+    pub fn handler(ctx: Context<InitializePsm>, args: InitializePsmArgs) -> Result<()> {
+        validate_bps(args.mint_fee_bps)?;
+        validate_bps(args.redeem_fee_bps)?;
+
+        ctx.accounts.psm.set_inner(Psm {
+            stable_mint: ctx.accounts.stable_mint.key(),
+            mint_fee_bps: args.mint_fee_bps,
+            redeem_fee_bps: args.redeem_fee_bps,
+            debt_ceiling: args.debt_ceiling,
+            debt: 0,
+            bump: ctx.bumps.psm,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{Config, Savings, CONFIG_SEED, MINT_SEED, SAVINGS_SEED, WAD};
+
+#[derive(Accounts)]
+pub struct InitializeSavings<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = Savings::DISCRIMINATOR.len() + Savings::INIT_SPACE,
+        seeds = [SAVINGS_SEED],
+        bump,
+    )]
+    pub savings: Account<'info, Savings>,
+    #[account(
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = savings,
+        associated_token::token_program = token_program
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl InitializeSavings<'_> {
+    pub fn handler(ctx: Context<InitializeSavings>) -> Result<()> {
+        ctx.accounts.savings.set_inner(Savings {
+            total_shares: 0,
+            chi: WAD,
+            last_drip_at: Clock::get()?.unix_timestamp,
+            bump: ctx.bumps.savings,
+        });
+
+        Ok(())
+    }
+}
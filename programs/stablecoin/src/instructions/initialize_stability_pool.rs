@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    Config, StabilityPool, CONFIG_SEED, MINT_SEED, STABILITY_POOL_PRECISION, STABILITY_POOL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct InitializeStabilityPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = StabilityPool::space(0),
+        seeds = [STABILITY_POOL_SEED],
+        bump,
+    )]
+    pub stability_pool: Account<'info, StabilityPool>,
+    #[account(
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = stability_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl InitializeStabilityPool<'_> {
+    pub fn handler(ctx: Context<InitializeStabilityPool>) -> Result<()> {
+        ctx.accounts.stability_pool.set_inner(StabilityPool {
+            total_deposits: 0,
+            p: STABILITY_POOL_PRECISION,
+            s: 0,
+            epoch: 0,
+            scale: 0,
+            bump: ctx.bumps.stability_pool,
+            scale_sums: vec![],
+        });
+
+        Ok(())
+    }
+}
@@ -2,22 +2,15 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
-use anchor_spl::{
-    associated_token::spl_associated_token_account::solana_program::native_token::LAMPORTS_PER_SOL,
-    token_2022::{burn_checked, BurnChecked},
-    token_interface::{Mint, TokenAccount, TokenInterface},
-};
-use switchboard_on_demand::{default_queue, get_slot};
-use switchboard_on_demand::{
-    prelude::rust_decimal::{prelude::ToPrimitive, Decimal},
-    SwitchboardQuote, SwitchboardQuoteExt,
-};
+use anchor_spl::token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface};
+use solana_stake_interface::program as stake_program;
+use switchboard_on_demand::default_queue;
 
 use crate::{
-    bps_to_decimal, calculate_health_factor, close, error::StablecoinError, get_oracle_quote,
-    get_price_from_quote, validate_above_min_health_factor, validate_price, vault_signer, Config,
-    Position, SafeMath, SafeMathAssign, CONFIG_SEED, MINT_DECIMALS, MINT_SEED, POSITION_SEED,
-    VAULT_SEED,
+    calculate_liquidation, close, error::StablecoinError, get_oracle_price, oracle_quote_address,
+    validate_price, validate_price_bounds, vault_signer, Config, Liquidation, OracleQuoteAccount,
+    Position, SafeMath, StakeSeizure, TreasuryFeeCollected, CONFIG_SEED, MINT_SEED, POSITION_SEED,
+    STAKE_SEED, TREASURY_SEED, VAULT_SEED,
 };
 
 #[derive(Accounts)]
@@ -28,6 +21,7 @@ pub struct LiquidatePosition<'info> {
     #[account(mut)]
     pub depositor: UncheckedAccount<'info>,
     #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump = config.bump,
     )]
@@ -44,25 +38,47 @@ pub struct LiquidatePosition<'info> {
     )]
     pub oracle_queue: UncheckedAccount<'info>,
     #[account(
-        address = oracle_quote.canonical_key(&default_queue())
+        address = oracle_quote_address(&oracle_quote)
     )]
-    pub oracle_quote: Box<Account<'info, SwitchboardQuote>>,
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
     #[account(
         mut,
         seeds = [VAULT_SEED, position.key().as_ref()],
         bump = position.vault_bump,
     )]
     pub vault: SystemAccount<'info>,
+    /// CHECK: Stake account of position, checked by stake program. Required if staked collateral
+    /// is seized
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, position.key().as_ref()],
+        bump = position.stake_bump,
+    )]
+    pub stake_account: Option<UncheckedAccount<'info>>,
+    /// New stake account receiving seized stake, handed to the liquidator
+    #[account(mut)]
+    pub seized_stake: Option<Signer<'info>>,
+    /// CHECK: Stake program
+    #[account(address = stake_program::ID)]
+    pub stake_program: Option<UncheckedAccount<'info>>,
     #[account(
         mut,
         seeds = [MINT_SEED],
         bump = config.mint_bump,
     )]
     pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Treasury, receives the treasury fee once initialized
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = liquidator,
+        associated_token::token_program = token_program,
     )]
     pub liquidator_token_account: InterfaceAccount<'info, TokenAccount>,
     pub system_program: Program<'info, System>,
@@ -78,7 +94,21 @@ pub struct LiquidatePosition<'info> {
 }
 
 impl<'info> LiquidatePosition<'info> {
-    pub fn handler(ctx: Context<LiquidatePosition>, amount_to_burn: u64) -> Result<()> {
+    /// Burns `amount_to_burn` of the position's debt for its collateral plus bonus, reverting if
+    /// the oracle price is outside the optional `min_price` and `max_price` bounds, scaled by
+    /// `WAD`, or if less than `min_collateral_out` lamports are paid to the liquidator.
+    ///
+    /// Collateral is paid from the vault first. Any remainder is split off the position stake into
+    /// `seized_stake`, which the liquidator funds and controls; the liquidator then pays the
+    /// treasury fee the vault could not cover. No treasury fee is charged until the treasury is
+    /// initialized.
+    pub fn handler(
+        ctx: Context<LiquidatePosition>,
+        amount_to_burn: u64,
+        min_price: Option<u128>,
+        max_price: Option<u128>,
+        min_collateral_out: Option<u64>,
+    ) -> Result<()> {
         let LiquidatePosition {
             position,
             config,
@@ -94,59 +124,88 @@ impl<'info> LiquidatePosition<'info> {
             token_program,
             clock,
             depositor,
+            treasury,
+            stake_account,
+            seized_stake,
+            stake_program,
             ..
         } = ctx.accounts;
 
-        let oracle_quote_data = oracle_quote.to_account_data().unwrap();
+        require!(
+            !position.is_in_auction(Clock::get()?.unix_timestamp),
+            StablecoinError::PositionInAuction
+        );
 
-        let quote = get_oracle_quote(
-            oracle_queue.to_account_info(),
-            slot_hashes_sysvar.to_account_info(),
-            instructions_sysvar.to_account_info(),
-            get_slot(&clock.to_account_info()),
-            &oracle_quote_data.as_slice(),
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
         )?;
 
-        let price = get_price_from_quote(quote)?;
-
         validate_price(price)?;
+        validate_price_bounds(price, min_price, max_price)?;
 
-        let mut health_factor =
-            calculate_health_factor(vault.lamports(), position.amount_minted, price)?;
+        let Liquidation {
+            collateral_seized: amount_to_liquidate,
+            mut treasury_fee,
+            remaining_debt,
+            ..
+        } = calculate_liquidation(
+            config,
+            position.collateral(vault.lamports())?,
+            position.amount_minted,
+            amount_to_burn,
+            price,
+        )?;
 
-        require_gt!(
-            bps_to_decimal(config.liquidation_threshold_bps)?,
-            health_factor,
-            StablecoinError::AboveLiquidationThreshold
-        );
+        if treasury.owner != &crate::ID {
+            treasury_fee = 0;
+        }
 
-        let lamports = Decimal::new(amount_to_burn as i64, MINT_DECIMALS as u32)
-            .safe_mul(LAMPORTS_PER_SOL.into())?
-            .safe_div(price)?;
-        let liquidation_bonus = lamports.safe_mul(bps_to_decimal(config.liquidation_bonus_bps)?)?;
-        let amount_to_liquidate = lamports
-            .safe_add(liquidation_bonus)?
-            .to_u64()
-            .ok_or(StablecoinError::ConversionFailed)?;
-
-        // vault balance is the max amount that can be liquidated
-        let lamport_balance = vault.lamports().saturating_sub(amount_to_liquidate);
-        position
-            .amount_minted
-            .safe_sub_assign(amount_to_burn)
-            .map_err(|_| StablecoinError::InsufficientAmountMinted)?;
-
-        health_factor = calculate_health_factor(lamport_balance, position.amount_minted, price)?;
-
-        validate_above_min_health_factor(
-            health_factor,
-            bps_to_decimal(config.min_loan_to_value_bps)?,
-        )?;
+        // liquid collateral is paid out first, the rest is seized from the stake
+        let vault_out = amount_to_liquidate.min(vault.lamports());
+        let stake_out = amount_to_liquidate.safe_sub(vault_out)?;
+        let vault_treasury_fee = treasury_fee.min(vault_out);
+
+        let collateral_out = amount_to_liquidate.safe_sub(treasury_fee)?;
+
+        if let Some(min_collateral_out) = min_collateral_out {
+            require_gte!(
+                collateral_out,
+                min_collateral_out,
+                StablecoinError::CollateralOutBelowMinimum
+            );
+        }
+
+        position.amount_minted = remaining_debt;
+
+        config.decrease_totals(amount_to_liquidate, amount_to_burn)?;
 
         let position_key = position.key();
         let vault_bump = position.vault_bump;
         let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
 
+        if stake_out > 0 {
+            let (Some(stake_account), Some(seized_stake), Some(stake_program)) =
+                (stake_account, seized_stake, stake_program)
+            else {
+                return err!(StablecoinError::MissingStakeAccounts);
+            };
+
+            StakeSeizure {
+                stake_account: &stake_account.to_account_info(),
+                seized_stake: &seized_stake.to_account_info(),
+                vault: &vault.to_account_info(),
+                recipient: &liquidator.to_account_info(),
+                clock: &clock.to_account_info(),
+                stake_program: &stake_program.to_account_info(),
+                system_program: &system_program.to_account_info(),
+            }
+            .seize(position, stake_out, vault_signer)?;
+        }
+
         transfer(
             CpiContext::new_with_signer(
                 system_program.to_account_info(),
@@ -156,9 +215,44 @@ impl<'info> LiquidatePosition<'info> {
                 },
                 &[vault_signer],
             ),
-            amount_to_liquidate,
+            vault_out.safe_sub(vault_treasury_fee)?,
         )?;
 
+        if treasury_fee > 0 {
+            if vault_treasury_fee > 0 {
+                transfer(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        Transfer {
+                            from: vault.to_account_info(),
+                            to: treasury.to_account_info(),
+                        },
+                        &[vault_signer],
+                    ),
+                    vault_treasury_fee,
+                )?;
+            }
+
+            // liquidator received the rest of the fee as stake
+            if treasury_fee > vault_treasury_fee {
+                transfer(
+                    CpiContext::new(
+                        system_program.to_account_info(),
+                        Transfer {
+                            from: liquidator.to_account_info(),
+                            to: treasury.to_account_info(),
+                        },
+                    ),
+                    treasury_fee.safe_sub(vault_treasury_fee)?,
+                )?;
+            }
+
+            emit!(TreasuryFeeCollected {
+                position: position_key,
+                lamports: treasury_fee,
+            });
+        }
+
         burn_checked(
             CpiContext::new(
                 token_program.to_account_info(),
@@ -172,8 +266,27 @@ impl<'info> LiquidatePosition<'info> {
             mint.decimals,
         )?;
 
-        // Close position if fully liquidated
-        if position.amount_minted == 0 {
+        // Return residual collateral to depositor and close position if fully liquidated and
+        // nothing is left staked
+        if position.amount_minted == 0 && position.staked_collateral == 0 {
+            let residual_lamports = vault.lamports();
+
+            if residual_lamports > 0 {
+                transfer(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        Transfer {
+                            from: vault.to_account_info(),
+                            to: depositor.to_account_info(),
+                        },
+                        &[vault_signer],
+                    ),
+                    residual_lamports,
+                )?;
+            }
+
+            config.decrease_totals(residual_lamports, 0)?;
+
             close(position.to_account_info(), depositor.to_account_info())?;
         }
 
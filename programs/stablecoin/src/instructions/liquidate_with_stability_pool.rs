@@ -0,0 +1,290 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface};
+use solana_stake_interface::program as stake_program;
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_collateral_for_debt, calculate_health_factor, close, error::StablecoinError,
+    get_oracle_price, oracle_quote_address, stability_pool_signer,
+    validate_above_min_health_factor, validate_price, vault_signer, Config, OracleQuoteAccount,
+    Position, SafeMath, SafeMathAssign, StabilityPool, StakeSeizure, Wad, CONFIG_SEED, MINT_SEED,
+    POSITION_SEED, STABILITY_POOL_SEED, STAKE_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct LiquidateWithStabilityPool<'info> {
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+    /// CHECK: depositor of position account to be liquidated
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Stake account of position, checked by stake program. Required if staked collateral
+    /// is seized
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, position.key().as_ref()],
+        bump = position.stake_bump,
+    )]
+    pub stake_account: Option<UncheckedAccount<'info>>,
+    /// New stake account receiving seized stake, handed to the liquidator
+    #[account(mut)]
+    pub seized_stake: Option<Signer<'info>>,
+    /// CHECK: Stake program
+    #[account(address = stake_program::ID)]
+    pub stake_program: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [STABILITY_POOL_SEED],
+        bump = stability_pool.bump,
+    )]
+    pub stability_pool: Account<'info, StabilityPool>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stability_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> LiquidateWithStabilityPool<'info> {
+    /// Burns `amount_to_burn` of stability pool deposits against the position's debt, moving its
+    /// collateral plus bonus to the pool.
+    ///
+    /// Collateral is paid from the vault first. Any remainder is split off the position stake into
+    /// `seized_stake`, which the liquidator funds and controls, and the liquidator pays the pool
+    /// the same amount in lamports.
+    pub fn handler(ctx: Context<LiquidateWithStabilityPool>, amount_to_burn: u64) -> Result<()> {
+        let LiquidateWithStabilityPool {
+            liquidator,
+            position,
+            config,
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            vault,
+            stability_pool,
+            pool_token_account,
+            system_program,
+            mint,
+            token_program,
+            clock,
+            depositor,
+            stake_account,
+            seized_stake,
+            stake_program,
+        } = ctx.accounts;
+
+        require!(
+            !position.is_in_auction(Clock::get()?.unix_timestamp),
+            StablecoinError::PositionInAuction
+        );
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let collateral = position.collateral(vault.lamports())?;
+        let mut health_factor = calculate_health_factor(collateral, position.amount_minted, price)?;
+
+        let recovery_mode = config.is_recovery_mode(price)?;
+
+        require_gt!(
+            Wad::from_bps(config.effective_liquidation_threshold_bps(recovery_mode)),
+            health_factor,
+            StablecoinError::AboveLiquidationThreshold
+        );
+
+        // pool absorbs underwater positions, so seized collateral is capped by position collateral
+        let amount_to_liquidate =
+            calculate_collateral_for_debt(amount_to_burn, price, config.liquidation_bonus_bps)?
+                .min(collateral);
+
+        // liquid collateral is paid out first, the rest is seized from the stake
+        let vault_out = amount_to_liquidate.min(vault.lamports());
+        let stake_out = amount_to_liquidate.safe_sub(vault_out)?;
+
+        let remaining_collateral = collateral.safe_sub(amount_to_liquidate)?;
+        position
+            .amount_minted
+            .safe_sub_assign(amount_to_burn)
+            .map_err(|_| StablecoinError::InsufficientAmountMinted)?;
+
+        health_factor =
+            calculate_health_factor(remaining_collateral, position.amount_minted, price)?;
+
+        validate_above_min_health_factor(
+            health_factor,
+            Wad::from_bps(config.min_loan_to_value_bps),
+        )?;
+
+        stability_pool.offset(amount_to_burn, amount_to_liquidate)?;
+        config.decrease_totals(amount_to_liquidate, amount_to_burn)?;
+
+        // sums of past scales are kept for deposits snapshotted in them, paid by the liquidator
+        // starting a new scale. Rent is topped up separately as pool lamports back collateral gains
+        let pool_info = stability_pool.to_account_info();
+        let pool_space = StabilityPool::space(stability_pool.scale_sums.len());
+
+        if pool_space > pool_info.data_len() {
+            let rent = Rent::get()?;
+
+            transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    Transfer {
+                        from: liquidator.to_account_info(),
+                        to: pool_info.clone(),
+                    },
+                ),
+                rent.minimum_balance(pool_space)
+                    .safe_sub(rent.minimum_balance(pool_info.data_len()))?,
+            )?;
+
+            pool_info.resize(pool_space)?;
+        }
+
+        let position_key = position.key();
+        let vault_bump = position.vault_bump;
+        let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+
+        if stake_out > 0 {
+            let (Some(stake_account), Some(seized_stake), Some(stake_program)) =
+                (stake_account, seized_stake, stake_program)
+            else {
+                return err!(StablecoinError::MissingStakeAccounts);
+            };
+
+            StakeSeizure {
+                stake_account: &stake_account.to_account_info(),
+                seized_stake: &seized_stake.to_account_info(),
+                vault: &vault.to_account_info(),
+                recipient: &liquidator.to_account_info(),
+                clock: &clock.to_account_info(),
+                stake_program: &stake_program.to_account_info(),
+                system_program: &system_program.to_account_info(),
+            }
+            .seize(position, stake_out, vault_signer)?;
+
+            // pool is paid in lamports for the stake handed to the liquidator
+            transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    Transfer {
+                        from: liquidator.to_account_info(),
+                        to: stability_pool.to_account_info(),
+                    },
+                ),
+                stake_out,
+            )?;
+        }
+
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: stability_pool.to_account_info(),
+                },
+                &[vault_signer],
+            ),
+            vault_out,
+        )?;
+
+        let pool_bump = stability_pool.bump;
+        let pool_signer: &[&[u8]] = stability_pool_signer!(pool_bump);
+
+        burn_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                BurnChecked {
+                    authority: stability_pool.to_account_info(),
+                    from: pool_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            )
+            .with_signer(&[pool_signer]),
+            amount_to_burn,
+            mint.decimals,
+        )?;
+
+        // Return residual collateral to depositor and close position if fully liquidated and
+        // nothing is left staked
+        if position.amount_minted == 0 && position.staked_collateral == 0 {
+            let residual_lamports = vault.lamports();
+
+            if residual_lamports > 0 {
+                transfer(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        Transfer {
+                            from: vault.to_account_info(),
+                            to: depositor.to_account_info(),
+                        },
+                        &[vault_signer],
+                    ),
+                    residual_lamports,
+                )?;
+            }
+
+            config.decrease_totals(residual_lamports, 0)?;
+
+            close(position.to_account_info(), depositor.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::StablecoinError, resize, Config, ConfigV0, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Config in a previous layout version, deserialized in handler
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateConfig<'_> {
+    /// Upgrades config to the current layout version, reallocating it to the current size.
+    pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+        let MigrateConfig {
+            payer,
+            config,
+            system_program,
+        } = ctx.accounts;
+
+        let legacy_config = {
+            let data = config.try_borrow_data()?;
+
+            require!(
+                data.starts_with(Config::DISCRIMINATOR),
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            require_eq!(
+                data.len(),
+                Config::DISCRIMINATOR.len() + ConfigV0::INIT_SPACE,
+                StablecoinError::AccountAlreadyMigrated
+            );
+
+            ConfigV0::deserialize(&mut &data[Config::DISCRIMINATOR.len()..])?
+        };
+
+        resize(
+            config.to_account_info(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            Config::DISCRIMINATOR.len() + Config::INIT_SPACE,
+        )?;
+
+        let mut data = config.try_borrow_mut_data()?;
+        Config::from(legacy_config).try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
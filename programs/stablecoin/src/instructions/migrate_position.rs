@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StablecoinError, resize, Config, Position, PositionV0, CONFIG_SEED, POSITION_SEED,
+    VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Depositor of position
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: Position in a previous layout version, deserialized in handler
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub position: UncheckedAccount<'info>,
+    #[account(
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigratePosition<'_> {
+    /// Upgrades position to the current layout version, reallocating it to the current size, and
+    /// adds it to system totals. Config must be migrated first.
+    pub fn handler(ctx: Context<MigratePosition>) -> Result<()> {
+        let MigratePosition {
+            payer,
+            config,
+            position,
+            vault,
+            system_program,
+            ..
+        } = ctx.accounts;
+
+        let legacy_position = {
+            let data = position.try_borrow_data()?;
+
+            require!(
+                data.starts_with(Position::DISCRIMINATOR),
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            require_eq!(
+                data.len(),
+                Position::DISCRIMINATOR.len() + PositionV0::INIT_SPACE,
+                StablecoinError::AccountAlreadyMigrated
+            );
+
+            PositionV0::deserialize(&mut &data[Position::DISCRIMINATOR.len()..])?
+        };
+
+        config.increase_totals(vault.lamports(), legacy_position.amount_minted)?;
+
+        resize(
+            position.to_account_info(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            Position::DISCRIMINATOR.len() + Position::INIT_SPACE,
+        )?;
+
+        let mut data = position.try_borrow_mut_data()?;
+        Position::from(legacy_position).try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
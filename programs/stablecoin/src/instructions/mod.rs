@@ -1,14 +1,113 @@
+pub mod add_to_blocklist;
+pub use add_to_blocklist::*;
+
+pub mod deleverage;
+pub use deleverage::*;
+
 pub mod deposit_collateral;
 pub use deposit_collateral::*;
 
+pub mod deposit_savings;
+pub use deposit_savings::*;
+
+pub mod deposit_stability_pool;
+pub use deposit_stability_pool::*;
+
+pub mod drip;
+pub use drip::*;
+
+pub mod flash_mint;
+pub use flash_mint::*;
+
+pub mod flash_repay;
+pub use flash_repay::*;
+
+pub mod freeze_account;
+pub use freeze_account::*;
+
+pub mod get_max_mintable;
+pub use get_max_mintable::*;
+
+pub mod get_max_withdrawable;
+pub use get_max_withdrawable::*;
+
+pub mod get_position_health;
+pub use get_position_health::*;
+
+pub mod harvest_transfer_fees;
+pub use harvest_transfer_fees::*;
+
 pub mod initialize_config;
 pub use initialize_config::*;
 
+pub mod initialize_psm;
+pub use initialize_psm::*;
+
+pub mod initialize_savings;
+pub use initialize_savings::*;
+
+pub mod initialize_stability_pool;
+pub use initialize_stability_pool::*;
+
+pub mod initialize_treasury;
+pub use initialize_treasury::*;
+
 pub mod liquidate_position;
 pub use liquidate_position::*;
 
+pub mod liquidate_with_stability_pool;
+pub use liquidate_with_stability_pool::*;
+
+pub mod migrate_config;
+pub use migrate_config::*;
+
+pub mod migrate_position;
+pub use migrate_position::*;
+
+pub mod psm_mint;
+pub use psm_mint::*;
+
+pub mod psm_redeem;
+pub use psm_redeem::*;
+
+pub mod redeem;
+pub use redeem::*;
+
+pub mod remove_from_blocklist;
+pub use remove_from_blocklist::*;
+
+pub mod stake_collateral;
+pub use stake_collateral::*;
+
+pub mod start_auction;
+pub use start_auction::*;
+
+pub mod take_auction;
+pub use take_auction::*;
+
+pub mod thaw_account;
+pub use thaw_account::*;
+
+pub mod unstake_collateral;
+pub use unstake_collateral::*;
+
 pub mod update_config;
 pub use update_config::*;
 
+pub mod update_psm;
+pub use update_psm::*;
+
 pub mod withdraw_collateral;
 pub use withdraw_collateral::*;
+
+pub mod withdraw_savings;
+pub use withdraw_savings::*;
+
+pub mod withdraw_stability_pool;
+pub use withdraw_stability_pool::*;
+
+pub mod withdraw_stake;
+pub use withdraw_stake::*;
+
+pub mod withdraw_treasury;
+pub use withdraw_treasury::*;
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+use crate::{
+    error::StablecoinError, mint_signer, scale_amount, validate_not_blocklisted, Config, Psm,
+    SafeMath, SafeMathAssign, BLOCKLIST_SEED, CONFIG_SEED, MAX_BASIS_POINTS, MINT_DECIMALS,
+    MINT_SEED, PSM_SEED,
+};
+
+#[derive(Accounts)]
+pub struct PsmMint<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: Blocklist entry of user, must not exist
+    #[account(
+        seeds = [BLOCKLIST_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub blocklist_entry: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [PSM_SEED, stable_mint.key().as_ref()],
+        bump = psm.bump,
+    )]
+    pub psm: Account<'info, Psm>,
+    #[account(
+        mint::token_program = stable_token_program,
+    )]
+    pub stable_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = stable_mint,
+        associated_token::authority = psm,
+        associated_token::token_program = stable_token_program
+    )]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = stable_mint,
+        associated_token::authority = user,
+        associated_token::token_program = stable_token_program
+    )]
+    pub user_stable_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub stable_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> PsmMint<'info> {
+    /// Swaps `amount` of stable token into stablecoin, less mint fee.
+    pub fn handler(ctx: Context<PsmMint>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, StablecoinError::InvalidSwapAmount);
+
+        let PsmMint {
+            user,
+            config,
+            psm,
+            stable_mint,
+            reserve,
+            user_stable_token_account,
+            mint,
+            user_token_account,
+            token_program,
+            stable_token_program,
+            blocklist_entry,
+            ..
+        } = ctx.accounts;
+
+        validate_not_blocklisted(blocklist_entry)?;
+
+        let gross_amount = scale_amount(amount, stable_mint.decimals, MINT_DECIMALS)?;
+        // fee is kept in reserve as surplus backing
+        let fee = (gross_amount as u128)
+            .safe_mul(psm.mint_fee_bps.into())?
+            .safe_div(MAX_BASIS_POINTS.into())? as u64;
+        let amount_to_mint = gross_amount.safe_sub(fee)?;
+
+        require_gt!(amount_to_mint, 0, StablecoinError::InvalidSwapAmount);
+
+        psm.debt.safe_add_assign(amount_to_mint)?;
+        config.surplus.safe_add_assign(fee)?;
+
+        require_gte!(
+            psm.debt_ceiling,
+            psm.debt,
+            StablecoinError::PsmDebtCeilingExceeded
+        );
+
+        transfer_checked(
+            CpiContext::new(
+                stable_token_program.to_account_info(),
+                TransferChecked {
+                    authority: user.to_account_info(),
+                    from: user_stable_token_account.to_account_info(),
+                    mint: stable_mint.to_account_info(),
+                    to: reserve.to_account_info(),
+                },
+            ),
+            amount,
+            stable_mint.decimals,
+        )?;
+
+        let mint_bump = config.mint_bump;
+        let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+        mint_to(
+            CpiContext::new(
+                token_program.to_account_info(),
+                MintTo {
+                    authority: mint.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: user_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[mint_signer]),
+            amount_to_mint,
+        )?;
+
+        Ok(())
+    }
+}
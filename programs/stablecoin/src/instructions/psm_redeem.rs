@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        burn_checked, transfer_checked, BurnChecked, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    error::StablecoinError, psm_signer, scale_amount, Config, Psm, SafeMath, SafeMathAssign,
+    CONFIG_SEED, MAX_BASIS_POINTS, MINT_DECIMALS, MINT_SEED, PSM_SEED,
+};
+
+#[derive(Accounts)]
+pub struct PsmRedeem<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [PSM_SEED, stable_mint.key().as_ref()],
+        bump = psm.bump,
+    )]
+    pub psm: Account<'info, Psm>,
+    #[account(
+        mint::token_program = stable_token_program,
+    )]
+    pub stable_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = stable_mint,
+        associated_token::authority = psm,
+        associated_token::token_program = stable_token_program
+    )]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = stable_mint,
+        associated_token::authority = user,
+        associated_token::token_program = stable_token_program
+    )]
+    pub user_stable_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub stable_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> PsmRedeem<'info> {
+    /// Swaps `amount` of stablecoin into stable token, less redeem fee.
+    pub fn handler(ctx: Context<PsmRedeem>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, StablecoinError::InvalidSwapAmount);
+
+        let PsmRedeem {
+            user,
+            config,
+            psm,
+            stable_mint,
+            reserve,
+            user_stable_token_account,
+            mint,
+            user_token_account,
+            token_program,
+            stable_token_program,
+            ..
+        } = ctx.accounts;
+
+        // fee is kept in reserve as surplus backing
+        let fee = (amount as u128)
+            .safe_mul(psm.redeem_fee_bps.into())?
+            .safe_div(MAX_BASIS_POINTS.into())? as u64;
+        let amount_out = scale_amount(amount.safe_sub(fee)?, MINT_DECIMALS, stable_mint.decimals)?;
+
+        require_gt!(amount_out, 0, StablecoinError::InvalidSwapAmount);
+        require_gte!(
+            reserve.amount,
+            amount_out,
+            StablecoinError::InsufficientPsmReserves
+        );
+
+        // stablecoin minted against collateral can also be redeemed while reserves last
+        psm.debt = psm.debt.saturating_sub(amount);
+        config.surplus.safe_add_assign(fee)?;
+
+        burn_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                BurnChecked {
+                    authority: user.to_account_info(),
+                    from: user_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        let stable_mint_key = stable_mint.key();
+        let psm_bump = psm.bump;
+        let psm_signer: &[&[u8]] = psm_signer!(stable_mint_key, psm_bump);
+
+        transfer_checked(
+            CpiContext::new(
+                stable_token_program.to_account_info(),
+                TransferChecked {
+                    authority: psm.to_account_info(),
+                    from: reserve.to_account_info(),
+                    mint: stable_mint.to_account_info(),
+                    to: user_stable_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[psm_signer]),
+            amount_out,
+            stable_mint.decimals,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    token::spl_token::native_mint,
+    token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface},
+};
+use solana_stake_interface::program as stake_program;
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_health_factor, error::StablecoinError, get_oracle_price, oracle_quote_address,
+    stake_signer, validate_price, vault_signer, Config, OracleQuoteAccount, Position, SafeMath,
+    SafeMathAssign, StakeSeizure, Wad, CONFIG_SEED, MAX_BASIS_POINTS, MINT_DECIMALS, MINT_SEED,
+    STAKE_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = redeemer,
+        associated_token::token_program = token_program,
+    )]
+    pub redeemer_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Stake program, required if staked collateral is redeemed
+    #[account(address = stake_program::ID)]
+    pub stake_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> Redeem<'info> {
+    /// Redeems up to `amount_to_redeem` for SOL at oracle price.
+    ///
+    /// Remaining accounts are `(position, vault)` pairs sorted by ascending health factor. Ordering
+    /// is only checked among the given pairs, not across all positions, so redeemers may skip
+    /// riskier positions. Positions below 100% collateral are rejected, as redeeming them at face
+    /// value would pay out of bad debt.
+    ///
+    /// Pairs of positions with staked collateral are followed by the position stake account and a
+    /// new seized stake account. Collateral is paid from the vault first, the remainder is split
+    /// off the stake into the seized stake account, which the redeemer funds and controls.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, Redeem<'info>>,
+        amount_to_redeem: u64,
+    ) -> Result<()> {
+        let Redeem {
+            redeemer,
+            config,
+            oracle_queue,
+            oracle_quote,
+            mint,
+            redeemer_token_account,
+            system_program,
+            token_program,
+            stake_program,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+        } = ctx.accounts;
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let fee_bps = config.redemption_fee_bps(now)?;
+        let min_rent = Rent::get()?.minimum_balance(0);
+
+        let mut amount_remaining = amount_to_redeem;
+        let mut last_health_factor = Wad::ZERO;
+
+        let mut remaining_accounts = ctx.remaining_accounts.iter();
+
+        while amount_remaining > 0 {
+            let Some(position_info) = remaining_accounts.next() else {
+                break;
+            };
+            let vault = remaining_accounts
+                .next()
+                .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+
+            let mut position = Account::<Position>::try_from(position_info)?;
+            let position_key = position.key();
+            let vault_bump = position.vault_bump;
+            let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+
+            require_keys_eq!(
+                vault.key(),
+                Pubkey::create_program_address(vault_signer, &crate::ID)
+                    .map_err(|_| StablecoinError::InvalidPositionAddress)?,
+                StablecoinError::InvalidPositionAddress
+            );
+            require!(
+                !position.is_in_auction(now),
+                StablecoinError::PositionInAuction
+            );
+
+            let stake_accounts = if position.staked_collateral > 0 {
+                let (Some(stake_account), Some(seized_stake)) =
+                    (remaining_accounts.next(), remaining_accounts.next())
+                else {
+                    return Err(ErrorCode::AccountNotEnoughKeys.into());
+                };
+                let stake_bump = position.stake_bump;
+
+                require_keys_eq!(
+                    stake_account.key(),
+                    Pubkey::create_program_address(
+                        stake_signer!(position_key, stake_bump),
+                        &crate::ID
+                    )
+                    .map_err(|_| StablecoinError::InvalidPositionAddress)?,
+                    StablecoinError::InvalidPositionAddress
+                );
+
+                Some((stake_account, seized_stake))
+            } else {
+                None
+            };
+
+            let health_factor = calculate_health_factor(
+                position.collateral(vault.lamports())?,
+                position.amount_minted,
+                price,
+            )?;
+
+            require_gte!(health_factor, Wad::ONE, StablecoinError::PositionUnderwater);
+            require_gte!(
+                health_factor,
+                last_health_factor,
+                StablecoinError::PositionsNotSortedByHealthFactor
+            );
+
+            last_health_factor = health_factor;
+
+            let mut debt = amount_remaining.min(position.amount_minted);
+            let mut lamports = Wad::from_amount(debt, MINT_DECIMALS)?
+                .safe_div(price)?
+                .to_amount_floor(native_mint::DECIMALS)?;
+
+            // vault must stay rent exempt, capping redeemable collateral
+            let vault_lamports = vault.lamports().saturating_sub(min_rent);
+            let available_lamports = vault_lamports.safe_add(position.staked_collateral)?;
+
+            if lamports > available_lamports {
+                lamports = available_lamports;
+                debt = Wad::from_amount(lamports, native_mint::DECIMALS)?
+                    .mul_ceil(price)?
+                    .to_amount_ceil(MINT_DECIMALS)?;
+            }
+
+            if debt == 0 {
+                continue;
+            }
+
+            // fee is left in vault for the redeemed position
+            let fee = (lamports as u128)
+                .safe_mul(fee_bps.into())?
+                .safe_div(MAX_BASIS_POINTS.into())? as u64;
+            let lamports_out = lamports.safe_sub(fee)?;
+
+            // liquid collateral is paid out first, the rest is seized from the stake
+            let vault_out = lamports_out.min(vault_lamports);
+            let stake_out = lamports_out.safe_sub(vault_out)?;
+
+            if stake_out > 0 {
+                let (Some((stake_account, seized_stake)), Some(stake_program)) =
+                    (stake_accounts, stake_program.as_ref())
+                else {
+                    return err!(StablecoinError::MissingStakeAccounts);
+                };
+
+                StakeSeizure {
+                    stake_account,
+                    seized_stake,
+                    vault,
+                    recipient: &redeemer.to_account_info(),
+                    clock: &clock.to_account_info(),
+                    stake_program: &stake_program.to_account_info(),
+                    system_program: &system_program.to_account_info(),
+                }
+                .seize(&mut position, stake_out, vault_signer)?;
+            }
+
+            position.amount_minted.safe_sub_assign(debt)?;
+            position.exit(&crate::ID)?;
+            amount_remaining.safe_sub_assign(debt)?;
+            config.decrease_totals(lamports_out, debt)?;
+
+            transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: redeemer.to_account_info(),
+                    },
+                    &[vault_signer],
+                ),
+                vault_out,
+            )?;
+        }
+
+        let amount_redeemed = amount_to_redeem.safe_sub(amount_remaining)?;
+
+        require_gt!(amount_redeemed, 0, StablecoinError::NothingToRedeem);
+
+        // base rate rises in proportion to the fraction of supply redeemed
+        let base_rate_increase = (amount_redeemed as u128)
+            .safe_mul(MAX_BASIS_POINTS.into())?
+            .safe_div(mint.supply.into())?
+            .safe_div(2)? as u16;
+
+        config.redemption_base_rate_bps = config
+            .decayed_redemption_base_rate_bps(now)?
+            .saturating_add(base_rate_increase)
+            .min(MAX_BASIS_POINTS);
+        config.last_redemption_at = now;
+
+        burn_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                BurnChecked {
+                    authority: redeemer.to_account_info(),
+                    from: redeemer_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            amount_redeemed,
+            mint.decimals,
+        )?;
+
+        Ok(())
+    }
+}
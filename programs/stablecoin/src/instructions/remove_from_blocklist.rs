@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{BlocklistEntry, Config, BLOCKLIST_SEED, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct RemoveFromBlocklist<'info> {
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = compliance_authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [BLOCKLIST_SEED, blocklist_entry.address.as_ref()],
+        bump = blocklist_entry.bump,
+        close = compliance_authority,
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+}
+
+impl RemoveFromBlocklist<'_> {
+    pub fn handler(_ctx: Context<RemoveFromBlocklist>) -> Result<()> {
+        Ok(())
+    }
+}
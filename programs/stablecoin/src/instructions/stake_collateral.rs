@@ -0,0 +1,147 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+};
+use solana_stake_interface::{
+    instruction::{delegate_stake, initialize},
+    program as stake_program,
+    state::{Authorized, Lockup, StakeStateV2},
+};
+
+use crate::{
+    create_pda_account, error::StablecoinError, stake_signer, vault_signer, Position, SafeMath,
+    POSITION_SEED, STAKE_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct StakeCollateral<'info> {
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Stake account of position, created and delegated in handler
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, position.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+    /// CHECK: Validator vote account, checked by stake program
+    pub vote_account: UncheckedAccount<'info>,
+    /// CHECK: Stake config account, checked by stake program
+    pub stake_config: UncheckedAccount<'info>,
+    /// CHECK: Clock sysvar
+    #[account(address = sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Rent sysvar
+    #[account(address = sysvar::rent::ID)]
+    pub rent: UncheckedAccount<'info>,
+    /// CHECK: Stake history sysvar
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+    /// CHECK: Stake program
+    #[account(address = stake_program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl StakeCollateral<'_> {
+    pub fn handler(ctx: Context<StakeCollateral>, collateral_amount: u64) -> Result<()> {
+        let StakeCollateral {
+            position,
+            vault,
+            stake_account,
+            vote_account,
+            stake_config,
+            clock,
+            rent,
+            stake_history,
+            stake_program,
+            system_program,
+            ..
+        } = ctx.accounts;
+
+        require!(
+            position.staked_collateral == 0,
+            StablecoinError::CollateralAlreadyStaked
+        );
+        require!(
+            !position.is_in_auction(Clock::get()?.unix_timestamp),
+            StablecoinError::PositionInAuction
+        );
+
+        let stake_space = StakeStateV2::size_of();
+
+        // stake program rejects delegations below its minimum
+        require_gt!(
+            collateral_amount,
+            Rent::get()?.minimum_balance(stake_space),
+            StablecoinError::InvalidCollateralAmount
+        );
+
+        let lamport_balance = vault.lamports().safe_sub(collateral_amount)?;
+        let min_rent = Rent::get()?.minimum_balance(vault.data_len());
+
+        require_gte!(
+            lamport_balance,
+            min_rent,
+            StablecoinError::RentBelowMinimumAfterWithdrawal
+        );
+
+        let position_key = position.key();
+        let vault_bump = position.vault_bump;
+        let stake_bump = ctx.bumps.stake_account;
+        let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+        let stake_signer: &[&[u8]] = stake_signer!(position_key, stake_bump);
+
+        create_pda_account(
+            vault.to_account_info(),
+            stake_account.to_account_info(),
+            system_program.to_account_info(),
+            collateral_amount,
+            stake_space,
+            &stake_program.key(),
+            &[vault_signer, stake_signer],
+        )?;
+
+        // vault is both staker and withdrawer, so only this program can move the stake
+        invoke(
+            &initialize(
+                &stake_account.key(),
+                &Authorized {
+                    staker: vault.key(),
+                    withdrawer: vault.key(),
+                },
+                &Lockup::default(),
+            ),
+            &[stake_account.to_account_info(), rent.to_account_info()],
+        )?;
+
+        invoke_signed(
+            &delegate_stake(&stake_account.key(), &vault.key(), &vote_account.key()),
+            &[
+                stake_account.to_account_info(),
+                vote_account.to_account_info(),
+                clock.to_account_info(),
+                stake_history.to_account_info(),
+                stake_config.to_account_info(),
+                vault.to_account_info(),
+            ],
+            &[vault_signer],
+        )?;
+
+        position.staked_collateral = collateral_amount;
+        position.stake_bump = stake_bump;
+
+        Ok(())
+    }
+}
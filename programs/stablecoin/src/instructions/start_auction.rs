@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_health_factor, error::StablecoinError, get_oracle_price, oracle_quote_address,
+    validate_price, Auction, Config, OracleQuoteAccount, Position, SafeMath, Wad, AUCTION_SEED,
+    CONFIG_SEED, MINT_DECIMALS, POSITION_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct StartAuction<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    /// CHECK: depositor of position account to be auctioned
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = Auction::DISCRIMINATOR.len() + Auction::INIT_SPACE,
+        seeds = [AUCTION_SEED, position.key().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    #[account(
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> StartAuction<'info> {
+    pub fn handler(ctx: Context<StartAuction>) -> Result<()> {
+        let StartAuction {
+            keeper,
+            config,
+            position,
+            auction,
+            oracle_queue,
+            oracle_quote,
+            vault,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            ..
+        } = ctx.accounts;
+
+        require_gt!(
+            config.auction_duration,
+            0,
+            StablecoinError::AuctionsDisabled
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // expired auctions can be restarted at a fresh price
+        require!(
+            !position.is_in_auction(now),
+            StablecoinError::AuctionInProgress
+        );
+
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
+        )?;
+
+        validate_price(price)?;
+
+        let health_factor = calculate_health_factor(
+            position.collateral(vault.lamports())?,
+            position.amount_minted,
+            price,
+        )?;
+
+        let recovery_mode = config.is_recovery_mode(price)?;
+
+        require_gt!(
+            Wad::from_bps(config.effective_liquidation_threshold_bps(recovery_mode)),
+            health_factor,
+            StablecoinError::AboveLiquidationThreshold
+        );
+
+        // prices of 1 SOL in stablecoin base units, rounded up against bidder
+        let start_price = price
+            .safe_mul(Wad::ONE.safe_add(Wad::from_bps(config.auction_premium_bps))?)?
+            .to_amount_ceil(MINT_DECIMALS)?;
+        let end_price = price
+            .safe_mul(Wad::ONE.safe_sub(Wad::from_bps(config.auction_discount_bps))?)?
+            .to_amount_ceil(MINT_DECIMALS)?;
+
+        // rent payer of a restarted auction is kept
+        let auction_keeper = if auction.keeper == Pubkey::default() {
+            keeper.key()
+        } else {
+            auction.keeper
+        };
+
+        auction.set_inner(Auction {
+            position: position.key(),
+            keeper: auction_keeper,
+            debt: position.amount_minted,
+            start_price,
+            end_price,
+            started_at: now,
+            duration: config.auction_duration,
+            bump: ctx.bumps.auction,
+        });
+
+        position.auction_ends_at = now.safe_add(config.auction_duration.into())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::spl_associated_token_account::solana_program::native_token::LAMPORTS_PER_SOL,
+    token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface},
+};
+use solana_stake_interface::program as stake_program;
+
+use crate::{
+    close, error::StablecoinError, vault_signer, Auction, Config, Position, SafeMath,
+    SafeMathAssign, StakeSeizure, AUCTION_SEED, CONFIG_SEED, MINT_SEED, POSITION_SEED, STAKE_SEED,
+    VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct TakeAuction<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    /// CHECK: depositor of position account being auctioned
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+    /// CHECK: keeper that started the auction, receives rent on close
+    #[account(
+        mut,
+        address = auction.keeper,
+    )]
+    pub keeper: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, position.key().as_ref()],
+        bump = auction.bump,
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Stake account of position, checked by stake program. Required if staked collateral
+    /// is seized
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, position.key().as_ref()],
+        bump = position.stake_bump,
+    )]
+    pub stake_account: Option<UncheckedAccount<'info>>,
+    /// New stake account receiving seized stake, handed to the bidder
+    #[account(mut)]
+    pub seized_stake: Option<Signer<'info>>,
+    /// CHECK: Stake program
+    #[account(address = stake_program::ID)]
+    pub stake_program: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bidder,
+        associated_token::token_program = token_program,
+    )]
+    pub bidder_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Clock sysvar
+    #[account(address = sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+}
+
+impl<'info> TakeAuction<'info> {
+    /// Buys position collateral at the current auction price for `amount_to_burn` of its debt.
+    ///
+    /// Collateral is paid from the vault first. Any remainder is split off the position stake into
+    /// `seized_stake`, which the bidder funds and controls.
+    pub fn handler(ctx: Context<TakeAuction>, amount_to_burn: u64) -> Result<()> {
+        let TakeAuction {
+            bidder,
+            depositor,
+            keeper,
+            config,
+            position,
+            auction,
+            vault,
+            mint,
+            bidder_token_account,
+            system_program,
+            token_program,
+            stake_account,
+            seized_stake,
+            stake_program,
+            clock,
+            ..
+        } = ctx.accounts;
+
+        let price = auction.current_price(Clock::get()?.unix_timestamp)?;
+
+        let mut amount_to_burn = amount_to_burn.min(auction.debt);
+        let collateral_out = (amount_to_burn as u128)
+            .safe_mul(LAMPORTS_PER_SOL.into())?
+            .safe_div(price.into())?;
+
+        // bid is capped by remaining collateral in vault and stake
+        let collateral = position.collateral(vault.lamports())?;
+
+        let collateral_out = if collateral_out > collateral.into() {
+            amount_to_burn = u64::try_from(
+                (collateral as u128)
+                    .safe_mul(price.into())?
+                    .safe_add((LAMPORTS_PER_SOL - 1).into())?
+                    .safe_div(LAMPORTS_PER_SOL.into())?,
+            )
+            .map_err(|_| StablecoinError::ConversionFailed)?;
+
+            collateral
+        } else {
+            u64::try_from(collateral_out).map_err(|_| StablecoinError::ConversionFailed)?
+        };
+
+        require_gt!(collateral_out, 0, StablecoinError::InvalidCollateralAmount);
+
+        auction.debt.safe_sub_assign(amount_to_burn)?;
+        position
+            .amount_minted
+            .safe_sub_assign(amount_to_burn)
+            .map_err(|_| StablecoinError::InsufficientAmountMinted)?;
+        config.decrease_totals(collateral_out, amount_to_burn)?;
+
+        let position_key = position.key();
+        let vault_bump = position.vault_bump;
+        let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+
+        // liquid collateral is paid out first, the rest is seized from the stake
+        let vault_out = collateral_out.min(vault.lamports());
+        let stake_out = collateral_out.safe_sub(vault_out)?;
+
+        if stake_out > 0 {
+            let (Some(stake_account), Some(seized_stake), Some(stake_program)) =
+                (stake_account, seized_stake, stake_program)
+            else {
+                return err!(StablecoinError::MissingStakeAccounts);
+            };
+
+            StakeSeizure {
+                stake_account: &stake_account.to_account_info(),
+                seized_stake: &seized_stake.to_account_info(),
+                vault: &vault.to_account_info(),
+                recipient: &bidder.to_account_info(),
+                clock: &clock.to_account_info(),
+                stake_program: &stake_program.to_account_info(),
+                system_program: &system_program.to_account_info(),
+            }
+            .seize(position, stake_out, vault_signer)?;
+        }
+
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: bidder.to_account_info(),
+                },
+                &[vault_signer],
+            ),
+            vault_out,
+        )?;
+
+        burn_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                BurnChecked {
+                    authority: bidder.to_account_info(),
+                    from: bidder_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            amount_to_burn,
+            mint.decimals,
+        )?;
+
+        // Close auction once debt is covered or collateral runs out
+        if auction.debt == 0 || position.collateral(vault.lamports())? == 0 {
+            position.auction_ends_at = 0;
+
+            close(auction.to_account_info(), keeper.to_account_info())?;
+        }
+
+        // Return residual collateral to depositor and close position if fully covered and
+        // nothing is left staked
+        if position.amount_minted == 0 && position.staked_collateral == 0 {
+            let residual_lamports = vault.lamports();
+
+            if residual_lamports > 0 {
+                transfer(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        Transfer {
+                            from: vault.to_account_info(),
+                            to: depositor.to_account_info(),
+                        },
+                        &[vault_signer],
+                    ),
+                    residual_lamports,
+                )?;
+            }
+
+            config.decrease_totals(residual_lamports, 0)?;
+
+            close(position.to_account_info(), depositor.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, thaw_account, Mint, TokenAccount, TokenInterface};
+
+use crate::{mint_signer, Config, CONFIG_SEED, MINT_SEED};
+
+#[derive(Accounts)]
+pub struct ThawAccount<'info> {
+    pub compliance_authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = compliance_authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ThawAccount<'_> {
+    pub fn handler(ctx: Context<ThawAccount>) -> Result<()> {
+        let ThawAccount {
+            config,
+            mint,
+            token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        let mint_bump = config.mint_bump;
+        let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+        thaw_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::ThawAccount {
+                account: token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: mint.to_account_info(),
+            },
+            &[mint_signer],
+        ))
+    }
+}
@@ -0,0 +1,126 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use solana_stake_interface::{instruction::deactivate_stake, program as stake_program};
+use switchboard_on_demand::default_queue;
+
+use crate::{
+    calculate_health_factor, error::StablecoinError, get_oracle_price, oracle_quote_address,
+    validate_price, vault_signer, Config, OracleQuoteAccount, Position, Wad, CONFIG_SEED,
+    POSITION_SEED, STAKE_SEED, VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct UnstakeCollateral<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: depositor of position account to be unstaked
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Stake account of position, checked by stake program
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, position.key().as_ref()],
+        bump = position.stake_bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+    /// CHECK: SwitchbordOnDemand QueueAccountData
+    #[account(
+        address = default_queue(),
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+    #[account(
+        address = oracle_quote_address(&oracle_quote)
+    )]
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
+    /// CHECK: Stake program
+    #[account(address = stake_program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    /// CHECK: Clock sysvar
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl UnstakeCollateral<'_> {
+    pub fn handler(ctx: Context<UnstakeCollateral>) -> Result<()> {
+        let UnstakeCollateral {
+            authority,
+            depositor,
+            config,
+            position,
+            vault,
+            stake_account,
+            oracle_queue,
+            oracle_quote,
+            stake_program,
+            clock,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+        } = ctx.accounts;
+
+        require_gt!(
+            position.staked_collateral,
+            0,
+            StablecoinError::CollateralNotStaked
+        );
+
+        // anyone can unstake a liquidatable position so its collateral can be seized
+        if authority.key() != depositor.key() {
+            let price = get_oracle_price(
+                oracle_queue,
+                oracle_quote,
+                slot_hashes_sysvar,
+                instructions_sysvar,
+                clock,
+            )?;
+
+            validate_price(price)?;
+
+            let health_factor = calculate_health_factor(
+                position.collateral(vault.lamports())?,
+                position.amount_minted,
+                price,
+            )?;
+            let recovery_mode = config.is_recovery_mode(price)?;
+
+            require_gt!(
+                Wad::from_bps(config.effective_liquidation_threshold_bps(recovery_mode)),
+                health_factor,
+                StablecoinError::AboveLiquidationThreshold
+            );
+        }
+
+        let position_key = position.key();
+        let vault_bump = position.vault_bump;
+        let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+
+        invoke_signed(
+            &deactivate_stake(&stake_account.key(), &vault.key()),
+            &[
+                stake_account.to_account_info(),
+                clock.to_account_info(),
+                vault.to_account_info(),
+                stake_program.to_account_info(),
+            ],
+            &[vault_signer],
+        )?;
+
+        Ok(())
+    }
+}
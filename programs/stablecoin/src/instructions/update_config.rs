@@ -1,12 +1,27 @@
 use anchor_lang::prelude::*;
 
-use crate::{validate_bps, validate_ltv, Config, CONFIG_SEED};
+use crate::{error::StablecoinError, validate_bps, validate_ltv, Config, CONFIG_SEED};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateConfigArgs {
+    pub compliance_authority: Option<Pubkey>,
     pub liquidation_threshold_bps: Option<u16>,
     pub liquidation_bonus_bps: Option<u16>,
     pub min_loan_to_value_bps: Option<u16>,
+    pub auction_duration: Option<u32>,
+    pub auction_premium_bps: Option<u16>,
+    pub auction_discount_bps: Option<u16>,
+    pub redemption_fee_bps: Option<u16>,
+    pub max_flash_mint: Option<u64>,
+    pub flash_mint_fee_bps: Option<u16>,
+    pub swap_adapter: Option<Pubkey>,
+    pub deleverage_slippage_bps: Option<u16>,
+    pub critical_collateral_ratio_bps: Option<u16>,
+    pub savings_rate_bps: Option<u16>,
+    pub treasury_fee_bps: Option<u16>,
+    pub treasury_sol_withdrawal_limit: Option<u64>,
+    pub treasury_token_withdrawal_limit: Option<u64>,
+    pub origination_fee_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
@@ -24,13 +39,32 @@ pub struct UpdateConfig<'info> {
 impl UpdateConfig<'_> {
     pub fn handler(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
         let UpdateConfigArgs {
+            compliance_authority,
             liquidation_bonus_bps,
             liquidation_threshold_bps,
             min_loan_to_value_bps,
+            auction_duration,
+            auction_premium_bps,
+            auction_discount_bps,
+            redemption_fee_bps,
+            max_flash_mint,
+            flash_mint_fee_bps,
+            swap_adapter,
+            deleverage_slippage_bps,
+            critical_collateral_ratio_bps,
+            savings_rate_bps,
+            treasury_fee_bps,
+            treasury_sol_withdrawal_limit,
+            treasury_token_withdrawal_limit,
+            origination_fee_bps,
         } = args;
 
         let config = &mut ctx.accounts.config;
 
+        if let Some(compliance_authority) = compliance_authority {
+            config.compliance_authority = compliance_authority;
+        }
+
         if let Some(liquidation_threshold_bps) = liquidation_threshold_bps {
             config.liquidation_threshold_bps = liquidation_threshold_bps;
         }
@@ -45,11 +79,97 @@ impl UpdateConfig<'_> {
             config.min_loan_to_value_bps = min_loan_to_value_bps;
         }
 
+        if let Some(auction_duration) = auction_duration {
+            config.auction_duration = auction_duration;
+        }
+
+        if let Some(auction_premium_bps) = auction_premium_bps {
+            validate_bps(auction_premium_bps)?;
+
+            config.auction_premium_bps = auction_premium_bps;
+        }
+
+        if let Some(auction_discount_bps) = auction_discount_bps {
+            validate_bps(auction_discount_bps)?;
+
+            config.auction_discount_bps = auction_discount_bps;
+        }
+
+        if let Some(redemption_fee_bps) = redemption_fee_bps {
+            validate_bps(redemption_fee_bps)?;
+
+            config.redemption_fee_bps = redemption_fee_bps;
+        }
+
+        if let Some(max_flash_mint) = max_flash_mint {
+            config.max_flash_mint = max_flash_mint;
+        }
+
+        if let Some(flash_mint_fee_bps) = flash_mint_fee_bps {
+            validate_bps(flash_mint_fee_bps)?;
+
+            config.flash_mint_fee_bps = flash_mint_fee_bps;
+        }
+
+        if let Some(swap_adapter) = swap_adapter {
+            require_keys_neq!(
+                swap_adapter,
+                crate::ID,
+                StablecoinError::InvalidSwapAdapterProgram
+            );
+
+            config.swap_adapter = swap_adapter;
+        }
+
+        if let Some(deleverage_slippage_bps) = deleverage_slippage_bps {
+            validate_bps(deleverage_slippage_bps)?;
+
+            config.deleverage_slippage_bps = deleverage_slippage_bps;
+        }
+
+        if let Some(critical_collateral_ratio_bps) = critical_collateral_ratio_bps {
+            config.critical_collateral_ratio_bps = critical_collateral_ratio_bps;
+        }
+
+        if let Some(savings_rate_bps) = savings_rate_bps {
+            validate_bps(savings_rate_bps)?;
+
+            config.savings_rate_bps = savings_rate_bps;
+        }
+
+        if let Some(treasury_fee_bps) = treasury_fee_bps {
+            validate_bps(treasury_fee_bps)?;
+
+            config.treasury_fee_bps = treasury_fee_bps;
+        }
+
+        if let Some(treasury_sol_withdrawal_limit) = treasury_sol_withdrawal_limit {
+            config.treasury_sol_withdrawal_limit = treasury_sol_withdrawal_limit;
+        }
+
+        if let Some(treasury_token_withdrawal_limit) = treasury_token_withdrawal_limit {
+            config.treasury_token_withdrawal_limit = treasury_token_withdrawal_limit;
+        }
+
+        if let Some(origination_fee_bps) = origination_fee_bps {
+            validate_bps(origination_fee_bps)?;
+
+            config.origination_fee_bps = origination_fee_bps;
+        }
+
         validate_ltv(
             config.min_loan_to_value_bps,
             config.liquidation_threshold_bps,
         )?;
 
+        if config.critical_collateral_ratio_bps > 0 {
+            require_gt!(
+                config.critical_collateral_ratio_bps,
+                config.liquidation_threshold_bps,
+                StablecoinError::InvalidCriticalCollateralRatio
+            );
+        }
+
         Ok(())
     }
 }
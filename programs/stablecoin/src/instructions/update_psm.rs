@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{validate_bps, Config, Psm, CONFIG_SEED, PSM_SEED};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePsmArgs {
+    pub mint_fee_bps: Option<u16>,
+    pub redeem_fee_bps: Option<u16>,
+    pub debt_ceiling: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePsm<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [PSM_SEED, psm.stable_mint.as_ref()],
+        bump = psm.bump,
+    )]
+    pub psm: Account<'info, Psm>,
+}
+
+impl UpdatePsm<'_> {
+    pub fn handler(ctx: Context<UpdatePsm>, args: UpdatePsmArgs) -> Result<()> {
+        let UpdatePsmArgs {
+            mint_fee_bps,
+            redeem_fee_bps,
+            debt_ceiling,
+        } = args;
+
+        let psm = &mut ctx.accounts.psm;
+
+        if let Some(mint_fee_bps) = mint_fee_bps {
+            validate_bps(mint_fee_bps)?;
+
+            psm.mint_fee_bps = mint_fee_bps;
+        }
+
+        if let Some(redeem_fee_bps) = redeem_fee_bps {
+            validate_bps(redeem_fee_bps)?;
+
+            psm.redeem_fee_bps = redeem_fee_bps;
+        }
+
+        if let Some(debt_ceiling) = debt_ceiling {
+            psm.debt_ceiling = debt_ceiling;
+        }
+
+        Ok(())
+    }
+}
@@ -4,15 +4,15 @@ use anchor_lang::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022::{burn_checked, BurnChecked},
-    token_interface::{Mint, TokenAccount, TokenInterface},
+    token_interface::{burn_checked, BurnChecked, Mint, TokenAccount, TokenInterface},
 };
-use switchboard_on_demand::{default_queue, get_slot, SwitchboardQuote, SwitchboardQuoteExt};
+use switchboard_on_demand::default_queue;
 
 use crate::{
-    bps_to_decimal, calculate_health_factor, error::StablecoinError, get_oracle_quote,
-    get_price_from_quote, validate_above_min_health_factor, validate_price, vault_signer, Config,
-    Position, SafeMath, SafeMathAssign, CONFIG_SEED, MINT_SEED, POSITION_SEED, VAULT_SEED,
+    calculate_health_factor, error::StablecoinError, get_oracle_price, oracle_quote_address,
+    validate_above_min_health_factor, validate_price, validate_price_bounds,
+    validate_system_collateral_ratio, vault_signer, Config, OracleQuoteAccount, Position, SafeMath,
+    SafeMathAssign, Wad, CONFIG_SEED, MINT_SEED, POSITION_SEED, VAULT_SEED,
 };
 
 #[derive(Accounts)]
@@ -20,6 +20,7 @@ pub struct WithdrawCollateral<'info> {
     #[account(mut)]
     pub depositor: Signer<'info>,
     #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump = config.bump,
     )]
@@ -36,9 +37,9 @@ pub struct WithdrawCollateral<'info> {
     )]
     pub oracle_queue: UncheckedAccount<'info>,
     #[account(
-        address = oracle_quote.canonical_key(&default_queue())
+        address = oracle_quote_address(&oracle_quote)
     )]
-    pub oracle_quote: Box<Account<'info, SwitchboardQuote>>,
+    pub oracle_quote: Box<Account<'info, OracleQuoteAccount>>,
     #[account(
         mut,
         seeds = [VAULT_SEED, position.key().as_ref()],
@@ -73,10 +74,14 @@ pub struct WithdrawCollateral<'info> {
 }
 
 impl<'info> WithdrawCollateral<'info> {
+    /// Burns `amount_to_burn` and withdraws `collateral_amount`, reverting if the oracle price is
+    /// outside the optional `min_price` and `max_price` bounds, scaled by `WAD`.
     pub fn handler(
         ctx: Context<WithdrawCollateral>,
         collateral_amount: u64,
         amount_to_burn: u64,
+        min_price: Option<u128>,
+        max_price: Option<u128>,
     ) -> Result<()> {
         let WithdrawCollateral {
             position,
@@ -95,29 +100,43 @@ impl<'info> WithdrawCollateral<'info> {
             ..
         } = ctx.accounts;
 
+        require!(
+            !position.is_in_auction(Clock::get()?.unix_timestamp),
+            StablecoinError::PositionInAuction
+        );
+
         let lamport_balance = vault.lamports().safe_sub(collateral_amount)?;
         position.amount_minted.safe_sub_assign(amount_to_burn)?;
 
-        let oracle_quote_data = oracle_quote.to_account_data().unwrap();
-
-        let quote = get_oracle_quote(
-            oracle_queue.to_account_info(),
-            slot_hashes_sysvar.to_account_info(),
-            instructions_sysvar.to_account_info(),
-            get_slot(&clock.to_account_info()),
-            &oracle_quote_data.as_slice(),
+        let price = get_oracle_price(
+            oracle_queue,
+            oracle_quote,
+            slot_hashes_sysvar,
+            instructions_sysvar,
+            clock,
         )?;
 
-        let price = get_price_from_quote(quote)?;
-
         validate_price(price)?;
+        validate_price_bounds(price, min_price, max_price)?;
 
-        let health_factor =
-            calculate_health_factor(lamport_balance, position.amount_minted, price)?;
+        let health_factor = calculate_health_factor(
+            position.collateral(lamport_balance)?,
+            position.amount_minted,
+            price,
+        )?;
 
         validate_above_min_health_factor(
             health_factor,
-            bps_to_decimal(config.min_loan_to_value_bps)?,
+            Wad::from_bps(config.min_loan_to_value_bps),
+        )?;
+
+        let system_collateral_ratio = config.system_collateral_ratio(price)?;
+        config.decrease_totals(collateral_amount, amount_to_burn)?;
+
+        validate_system_collateral_ratio(
+            system_collateral_ratio,
+            config.system_collateral_ratio(price)?,
+            Wad::from_bps(config.critical_collateral_ratio_bps),
         )?;
 
         let min_rent = Rent::get()?.minimum_balance(vault.data_len());
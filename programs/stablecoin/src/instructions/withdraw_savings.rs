@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+use crate::{
+    error::StablecoinError, mint_signer, savings_signer, Config, SafeMath, Savings, SavingsDeposit,
+    CONFIG_SEED, MINT_SEED, SAVINGS_DEPOSIT_SEED, SAVINGS_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawSavings<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [SAVINGS_SEED],
+        bump = savings.bump,
+    )]
+    pub savings: Account<'info, Savings>,
+    #[account(
+        mut,
+        seeds = [SAVINGS_DEPOSIT_SEED, depositor.key().as_ref()],
+        bump = savings_deposit.bump,
+    )]
+    pub savings_deposit: Account<'info, SavingsDeposit>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = savings,
+        associated_token::token_program = token_program
+    )]
+    pub savings_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawSavings<'info> {
+    /// Drips accrued interest, then withdraws up to `amount` of the deposit's value.
+    pub fn handler(ctx: Context<WithdrawSavings>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, StablecoinError::InvalidSavingsAmount);
+
+        let WithdrawSavings {
+            config,
+            savings,
+            savings_deposit,
+            mint,
+            depositor_token_account,
+            savings_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        let interest = savings.drip(config, Clock::get()?.unix_timestamp)?;
+
+        if interest > 0 {
+            let mint_bump = config.mint_bump;
+            let mint_signer: &[&[u8]] = mint_signer!(mint_bump);
+
+            mint_to(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    MintTo {
+                        authority: mint.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: savings_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[mint_signer]),
+                interest,
+            )?;
+        }
+
+        let balance = savings.shares_to_amount(savings_deposit.shares)?;
+        let (amount, shares) = if amount >= balance {
+            (balance, savings_deposit.shares)
+        } else {
+            // shares are rounded up so withdrawals never take value from other deposits
+            (amount, savings.amount_to_shares_ceil(amount)?)
+        };
+
+        require_gt!(amount, 0, StablecoinError::InvalidSavingsAmount);
+
+        savings_deposit.shares = savings_deposit.shares.safe_sub(shares)?;
+        savings.total_shares = savings.total_shares.safe_sub(shares)?;
+
+        let savings_bump = savings.bump;
+        let savings_signer: &[&[u8]] = savings_signer!(savings_bump);
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: savings.to_account_info(),
+                    from: savings_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: depositor_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[savings_signer]),
+            amount,
+            mint.decimals,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    stability_pool_signer, Config, SafeMath, StabilityDeposit, StabilityPool, CONFIG_SEED,
+    MINT_SEED, STABILITY_DEPOSIT_SEED, STABILITY_POOL_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawStabilityPool<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [STABILITY_POOL_SEED],
+        bump = stability_pool.bump,
+    )]
+    pub stability_pool: Account<'info, StabilityPool>,
+    #[account(
+        mut,
+        seeds = [STABILITY_DEPOSIT_SEED, depositor.key().as_ref()],
+        bump = stability_deposit.bump,
+    )]
+    pub stability_deposit: Account<'info, StabilityDeposit>,
+    #[account(
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stability_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawStabilityPool<'info> {
+    /// Withdraws up to `amount` of compounded deposit along with all collateral gains.
+    pub fn handler(ctx: Context<WithdrawStabilityPool>, amount: u64) -> Result<()> {
+        let WithdrawStabilityPool {
+            depositor,
+            stability_pool,
+            stability_deposit,
+            mint,
+            depositor_token_account,
+            pool_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        let compounded_amount = stability_deposit.compounded_amount(stability_pool)?;
+        let collateral_gain = stability_deposit.collateral_gain(stability_pool)?;
+        let amount = amount.min(compounded_amount);
+
+        stability_deposit.snapshot(stability_pool, compounded_amount.safe_sub(amount)?);
+        // compounded deposits are rounded down so their sum never exceeds total deposits
+        stability_pool.total_deposits = stability_pool.total_deposits.saturating_sub(amount);
+
+        if collateral_gain > 0 {
+            stability_pool.sub_lamports(collateral_gain)?;
+            depositor.add_lamports(collateral_gain)?;
+        }
+
+        if amount > 0 {
+            let pool_bump = stability_pool.bump;
+            let pool_signer: &[&[u8]] = stability_pool_signer!(pool_bump);
+
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: stability_pool.to_account_info(),
+                        from: pool_token_account.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: depositor_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[pool_signer]),
+                amount,
+                mint.decimals,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use solana_stake_interface::{instruction::withdraw, program as stake_program};
+
+use crate::{
+    error::StablecoinError, vault_signer, Config, Position, CONFIG_SEED, POSITION_SEED, STAKE_SEED,
+    VAULT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    /// CHECK: depositor of position account whose stake is withdrawn
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, depositor.key().as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, position.key().as_ref()],
+        bump = position.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Stake account of position, checked by stake program
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, position.key().as_ref()],
+        bump = position.stake_bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+    /// CHECK: Stake program
+    #[account(address = stake_program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    /// CHECK: Clock sysvar
+    #[account(address = sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: Stake history sysvar
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+}
+
+impl WithdrawStake<'_> {
+    pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
+        let WithdrawStake {
+            config,
+            position,
+            vault,
+            stake_account,
+            stake_program,
+            clock,
+            stake_history,
+            ..
+        } = ctx.accounts;
+
+        require_gt!(
+            position.staked_collateral,
+            0,
+            StablecoinError::CollateralNotStaked
+        );
+
+        let position_key = position.key();
+        let vault_bump = position.vault_bump;
+        let vault_signer: &[&[u8]] = vault_signer!(position_key, vault_bump);
+        let stake_lamports = stake_account.lamports();
+
+        // fails until the stake is fully deactivated
+        invoke_signed(
+            &withdraw(
+                &stake_account.key(),
+                &vault.key(),
+                &vault.key(),
+                stake_lamports,
+                None,
+            ),
+            &[
+                stake_account.to_account_info(),
+                vault.to_account_info(),
+                clock.to_account_info(),
+                stake_history.to_account_info(),
+                stake_program.to_account_info(),
+            ],
+            &[vault_signer],
+        )?;
+
+        // staking rewards stay in the vault as collateral of the depositor
+        if stake_lamports > position.staked_collateral {
+            config.increase_totals(stake_lamports - position.staked_collateral, 0)?;
+        } else {
+            config.decrease_totals(position.staked_collateral - stake_lamports, 0)?;
+        }
+
+        position.staked_collateral = 0;
+
+        Ok(())
+    }
+}
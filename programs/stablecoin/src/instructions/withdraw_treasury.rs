@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    error::StablecoinError, treasury_signer, Config, SafeMath, Treasury, TreasuryWithdrawn,
+    CONFIG_SEED, MINT_SEED, TREASURY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Any account can receive treasury withdrawals
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        seeds = [MINT_SEED],
+        bump = config.mint_bump,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    /// Withdraws `lamports` and `amount` of stablecoin to recipient, within the per-epoch limits
+    /// on config.
+    pub fn handler(ctx: Context<WithdrawTreasury>, lamports: u64, amount: u64) -> Result<()> {
+        require!(
+            lamports > 0 || amount > 0,
+            StablecoinError::InvalidTreasuryWithdrawalAmount
+        );
+
+        let WithdrawTreasury {
+            recipient,
+            config,
+            treasury,
+            mint,
+            treasury_token_account,
+            recipient_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        let epoch = Clock::get()?.epoch;
+
+        treasury.record_withdrawal(
+            epoch,
+            lamports,
+            amount,
+            config.treasury_sol_withdrawal_limit,
+            config.treasury_token_withdrawal_limit,
+        )?;
+
+        if lamports > 0 {
+            let min_rent = Rent::get()?.minimum_balance(treasury.to_account_info().data_len());
+
+            require_gte!(
+                treasury.get_lamports().safe_sub(lamports)?,
+                min_rent,
+                StablecoinError::RentBelowMinimumAfterWithdrawal
+            );
+
+            treasury.sub_lamports(lamports)?;
+            recipient.add_lamports(lamports)?;
+        }
+
+        if amount > 0 {
+            let treasury_bump = treasury.bump;
+            let treasury_signer: &[&[u8]] = treasury_signer!(treasury_bump);
+
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: treasury.to_account_info(),
+                        from: treasury_token_account.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: recipient_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[treasury_signer]),
+                amount,
+                mint.decimals,
+            )?;
+        }
+
+        emit!(TreasuryWithdrawn {
+            recipient: recipient.key(),
+            lamports,
+            amount,
+            epoch,
+            sol_withdrawn: treasury.sol_withdrawn,
+            token_withdrawn: treasury.token_withdrawn,
+        });
+
+        Ok(())
+    }
+}
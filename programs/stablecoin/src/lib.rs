@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -7,6 +8,7 @@ pub mod utils;
 use anchor_lang::prelude::*;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -32,19 +34,187 @@ pub mod stablecoin {
         ctx: Context<DepositCollateral>,
         amount_collateral: u64,
         amount_to_mint: u64,
+        min_price: Option<u128>,
+        max_price: Option<u128>,
     ) -> Result<()> {
-        DepositCollateral::handler(ctx, amount_collateral, amount_to_mint)
+        DepositCollateral::handler(ctx, amount_collateral, amount_to_mint, min_price, max_price)
     }
 
     pub fn withdraw_collateral(
         ctx: Context<WithdrawCollateral>,
         amount_collateral: u64,
         amount_to_burn: u64,
+        min_price: Option<u128>,
+        max_price: Option<u128>,
     ) -> Result<()> {
-        WithdrawCollateral::handler(ctx, amount_collateral, amount_to_burn)
+        WithdrawCollateral::handler(ctx, amount_collateral, amount_to_burn, min_price, max_price)
     }
 
-    pub fn liquidate_position(ctx: Context<LiquidatePosition>, amount_to_burn: u64) -> Result<()> {
-        LiquidatePosition::handler(ctx, amount_to_burn)
+    pub fn liquidate_position(
+        ctx: Context<LiquidatePosition>,
+        amount_to_burn: u64,
+        min_price: Option<u128>,
+        max_price: Option<u128>,
+        min_collateral_out: Option<u64>,
+    ) -> Result<()> {
+        LiquidatePosition::handler(
+            ctx,
+            amount_to_burn,
+            min_price,
+            max_price,
+            min_collateral_out,
+        )
+    }
+
+    pub fn start_auction(ctx: Context<StartAuction>) -> Result<()> {
+        StartAuction::handler(ctx)
+    }
+
+    pub fn take_auction(ctx: Context<TakeAuction>, amount_to_burn: u64) -> Result<()> {
+        TakeAuction::handler(ctx, amount_to_burn)
+    }
+
+    pub fn initialize_stability_pool(ctx: Context<InitializeStabilityPool>) -> Result<()> {
+        InitializeStabilityPool::handler(ctx)
+    }
+
+    pub fn deposit_stability_pool(ctx: Context<DepositStabilityPool>, amount: u64) -> Result<()> {
+        DepositStabilityPool::handler(ctx, amount)
+    }
+
+    pub fn withdraw_stability_pool(ctx: Context<WithdrawStabilityPool>, amount: u64) -> Result<()> {
+        WithdrawStabilityPool::handler(ctx, amount)
+    }
+
+    pub fn liquidate_with_stability_pool(
+        ctx: Context<LiquidateWithStabilityPool>,
+        amount_to_burn: u64,
+    ) -> Result<()> {
+        LiquidateWithStabilityPool::handler(ctx, amount_to_burn)
+    }
+
+    pub fn redeem<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Redeem<'info>>,
+        amount_to_redeem: u64,
+    ) -> Result<()> {
+        Redeem::handler(ctx, amount_to_redeem)
+    }
+
+    pub fn initialize_psm(ctx: Context<InitializePsm>, args: InitializePsmArgs) -> Result<()> {
+        InitializePsm::handler(ctx, args)
+    }
+
+    pub fn update_psm(ctx: Context<UpdatePsm>, args: UpdatePsmArgs) -> Result<()> {
+        UpdatePsm::handler(ctx, args)
+    }
+
+    pub fn psm_mint(ctx: Context<PsmMint>, amount: u64) -> Result<()> {
+        PsmMint::handler(ctx, amount)
+    }
+
+    pub fn psm_redeem(ctx: Context<PsmRedeem>, amount: u64) -> Result<()> {
+        PsmRedeem::handler(ctx, amount)
+    }
+
+    pub fn flash_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FlashMint<'info>>,
+        amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        FlashMint::handler(ctx, amount, data)
+    }
+
+    pub fn flash_repay(ctx: Context<FlashRepay>, amount: u64) -> Result<()> {
+        FlashRepay::handler(ctx, amount)
+    }
+
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestTransferFees<'info>>,
+    ) -> Result<()> {
+        HarvestTransferFees::handler(ctx)
+    }
+
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        FreezeAccount::handler(ctx)
+    }
+
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        ThawAccount::handler(ctx)
+    }
+
+    pub fn add_to_blocklist(ctx: Context<AddToBlocklist>, address: Pubkey) -> Result<()> {
+        AddToBlocklist::handler(ctx, address)
+    }
+
+    pub fn remove_from_blocklist(ctx: Context<RemoveFromBlocklist>) -> Result<()> {
+        RemoveFromBlocklist::handler(ctx)
+    }
+
+    pub fn deleverage<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Deleverage<'info>>,
+        collateral_amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        Deleverage::handler(ctx, collateral_amount, data)
+    }
+
+    pub fn get_position_health(ctx: Context<GetPositionHealth>) -> Result<PositionHealth> {
+        GetPositionHealth::handler(ctx)
+    }
+
+    pub fn get_max_mintable(ctx: Context<GetMaxMintable>) -> Result<u64> {
+        GetMaxMintable::handler(ctx)
+    }
+
+    pub fn get_max_withdrawable(ctx: Context<GetMaxWithdrawable>) -> Result<u64> {
+        GetMaxWithdrawable::handler(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        MigrateConfig::handler(ctx)
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        MigratePosition::handler(ctx)
+    }
+
+    pub fn stake_collateral(ctx: Context<StakeCollateral>, collateral_amount: u64) -> Result<()> {
+        StakeCollateral::handler(ctx, collateral_amount)
+    }
+
+    pub fn unstake_collateral(ctx: Context<UnstakeCollateral>) -> Result<()> {
+        UnstakeCollateral::handler(ctx)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        WithdrawStake::handler(ctx)
+    }
+
+    pub fn initialize_savings(ctx: Context<InitializeSavings>) -> Result<()> {
+        InitializeSavings::handler(ctx)
+    }
+
+    pub fn deposit_savings(ctx: Context<DepositSavings>, amount: u64) -> Result<()> {
+        DepositSavings::handler(ctx, amount)
+    }
+
+    pub fn withdraw_savings(ctx: Context<WithdrawSavings>, amount: u64) -> Result<()> {
+        WithdrawSavings::handler(ctx, amount)
+    }
+
+    pub fn drip(ctx: Context<Drip>) -> Result<()> {
+        Drip::handler(ctx)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        InitializeTreasury::handler(ctx)
+    }
+
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        lamports: u64,
+        amount: u64,
+    ) -> Result<()> {
+        WithdrawTreasury::handler(ctx, lamports, amount)
     }
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::StablecoinError, SafeMath};
+
+/// Dutch auction selling the collateral of an unhealthy position.
+#[account]
+#[derive(InitSpace)]
+pub struct Auction {
+    /// Position whose collateral is being auctioned.
+    pub position: Pubkey,
+    /// Address that started the auction and receives rent on close.
+    pub keeper: Pubkey,
+    /// Remaining debt to be covered by bidders, in stablecoin base units.
+    pub debt: u64,
+    /// Price of 1 SOL at the start of the auction, in stablecoin base units.
+    pub start_price: u64,
+    /// Price of 1 SOL at the end of the auction, in stablecoin base units.
+    pub end_price: u64,
+    /// Unix timestamp at which the auction started.
+    pub started_at: i64,
+    /// Duration over which the price decays, in seconds.
+    pub duration: u32,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
+
+impl Auction {
+    /// Linearly decays price from `start_price` to `end_price` over `duration`.
+    pub fn current_price(&self, now: i64) -> Result<u64> {
+        require_gt!(
+            self.started_at.safe_add(self.duration.into())?,
+            now,
+            StablecoinError::AuctionExpired
+        );
+
+        let elapsed = now.safe_sub(self.started_at)?.max(0) as u128;
+        let decay = (self.start_price.safe_sub(self.end_price)? as u128)
+            .safe_mul(elapsed)?
+            .safe_div(self.duration.into())?;
+
+        self.start_price.safe_sub(decay as u64)
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Marks an address as blocked from depositing and minting. Existence of the account is the flag.
+#[account]
+#[derive(InitSpace)]
+pub struct BlocklistEntry {
+    /// Blocked address.
+    pub address: Pubkey,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
@@ -1,19 +1,226 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    calculate_health_factor, SafeMath, Wad, CONFIG_VERSION, MAX_BASIS_POINTS,
+    REDEMPTION_BASE_RATE_HALF_LIFE,
+};
+
 /// Config account storing protocol-wide settings.
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
+    /// Layout version of the account, upgraded by `migrate_config`.
+    pub version: u8,
     /// Address that can update protocol configurations.
     pub authority: Pubkey,
+    /// Address that can freeze token accounts and manage the blocklist.
+    pub compliance_authority: Pubkey,
     /// Minimum LTV that a position must maintain, in basis points.
     pub min_loan_to_value_bps: u16,
     /// Minimum LTV at which a position can be liquidated, in basis points.
     pub liquidation_threshold_bps: u16,
     /// Bonus collateral that can be liquidated, in basis points.
     pub liquidation_bonus_bps: u16,
+    /// Duration over which auction price decays, in seconds. Auctions are disabled if 0.
+    pub auction_duration: u32,
+    /// Premium over oracle price at which auctions start, in basis points.
+    pub auction_premium_bps: u16,
+    /// Discount from oracle price at which auctions end, in basis points.
+    pub auction_discount_bps: u16,
+    /// Minimum fee charged on redemptions, in basis points.
+    pub redemption_fee_bps: u16,
+    /// Fee added on top of minimum after heavy redemption activity, in basis points.
+    pub redemption_base_rate_bps: u16,
+    /// Unix timestamp of the last redemption.
+    pub last_redemption_at: i64,
+    /// Maximum stablecoin that can be flash minted in one transaction. Flash mints are disabled if 0.
+    pub max_flash_mint: u64,
+    /// Fee charged on flash mints, in basis points.
+    pub flash_mint_fee_bps: u16,
+    /// Program selling collateral for stablecoin in `deleverage`. Deleveraging is disabled if default.
+    pub swap_adapter: Pubkey,
+    /// Maximum shortfall of deleverage swap proceeds from oracle price, in basis points.
+    pub deleverage_slippage_bps: u16,
     /// Bump used for seed derivation.
     pub bump: u8,
     /// Bump used for mint seed derivation.
     pub mint_bump: u8,
+    /// System collateral ratio below which recovery mode applies, in basis points. Recovery mode is
+    /// disabled if 0.
+    pub critical_collateral_ratio_bps: u16,
+    /// Sum of lamports held in all position vaults and stake accounts.
+    pub total_collateral: u64,
+    /// Sum of stablecoin minted against all positions.
+    pub total_debt: u64,
+    /// Annual rate paid to savings deposits, in basis points. Savings interest stops accruing if 0.
+    pub savings_rate_bps: u16,
+    /// Fee income not yet paid out as savings interest, in stablecoin base units.
+    pub surplus: u64,
+    /// Share of the liquidation bonus sent to the treasury, in basis points.
+    pub treasury_fee_bps: u16,
+    /// Maximum lamports that can be withdrawn from the treasury per epoch. SOL withdrawals are
+    /// disabled if 0.
+    pub treasury_sol_withdrawal_limit: u64,
+    /// Maximum stablecoin that can be withdrawn from the treasury per epoch. Stablecoin withdrawals
+    /// are disabled if 0.
+    pub treasury_token_withdrawal_limit: u64,
+    /// Minimum fee added to the debt of minted stablecoin, in basis points. Raised by the redemption
+    /// base rate, so borrowing gets more expensive after heavy redemption activity. Origination
+    /// fees are disabled if 0.
+    pub origination_fee_bps: u16,
+    /// Reserved for fields added in later layout versions.
+    pub reserved: [u8; 80],
+}
+
+/// `Config` layout before versioning was introduced, as written by the initial release.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct ConfigV0 {
+    pub authority: Pubkey,
+    pub min_loan_to_value_bps: u16,
+    pub liquidation_threshold_bps: u16,
+    pub liquidation_bonus_bps: u16,
+    pub bump: u8,
+    pub mint_bump: u8,
+}
+
+impl From<ConfigV0> for Config {
+    fn from(config: ConfigV0) -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            authority: config.authority,
+            compliance_authority: config.authority,
+            min_loan_to_value_bps: config.min_loan_to_value_bps,
+            liquidation_threshold_bps: config.liquidation_threshold_bps,
+            liquidation_bonus_bps: config.liquidation_bonus_bps,
+            auction_duration: 0,
+            auction_premium_bps: 0,
+            auction_discount_bps: 0,
+            redemption_fee_bps: 0,
+            redemption_base_rate_bps: 0,
+            last_redemption_at: 0,
+            max_flash_mint: 0,
+            flash_mint_fee_bps: 0,
+            swap_adapter: Pubkey::default(),
+            deleverage_slippage_bps: 0,
+            bump: config.bump,
+            mint_bump: config.mint_bump,
+            critical_collateral_ratio_bps: 0,
+            total_collateral: 0,
+            total_debt: 0,
+            savings_rate_bps: 0,
+            surplus: 0,
+            treasury_fee_bps: 0,
+            treasury_sol_withdrawal_limit: 0,
+            treasury_token_withdrawal_limit: 0,
+            origination_fee_bps: 0,
+            reserved: [0; 80],
+        }
+    }
+}
+
+impl Config {
+    /// Redemption base rate after halving once per elapsed half-life.
+    pub fn decayed_redemption_base_rate_bps(&self, now: i64) -> Result<u16> {
+        let half_lives = now
+            .safe_sub(self.last_redemption_at)?
+            .max(0)
+            .safe_div(REDEMPTION_BASE_RATE_HALF_LIFE)?;
+
+        Ok(self
+            .redemption_base_rate_bps
+            .checked_shr(half_lives.min(u16::BITS.into()) as u32)
+            .unwrap_or(0))
+    }
+
+    /// Flash mint fee for `amount`, rounded up.
+    pub fn flash_mint_fee(&self, amount: u64) -> Result<u64> {
+        Ok((amount as u128)
+            .safe_mul(self.flash_mint_fee_bps.into())?
+            .safe_add((MAX_BASIS_POINTS - 1).into())?
+            .safe_div(MAX_BASIS_POINTS.into())? as u64)
+    }
+
+    /// Total origination fee at `now`, capped at 100%.
+    pub fn origination_fee_bps(&self, now: i64) -> Result<u16> {
+        if self.origination_fee_bps == 0 {
+            return Ok(0);
+        }
+
+        Ok(self
+            .origination_fee_bps
+            .saturating_add(self.decayed_redemption_base_rate_bps(now)?)
+            .min(MAX_BASIS_POINTS))
+    }
+
+    /// Origination fee for minting `amount` at `now`, rounded up.
+    pub fn origination_fee(&self, amount: u64, now: i64) -> Result<u64> {
+        Ok((amount as u128)
+            .safe_mul(self.origination_fee_bps(now)?.into())?
+            .safe_add((MAX_BASIS_POINTS - 1).into())?
+            .safe_div(MAX_BASIS_POINTS.into())? as u64)
+    }
+
+    /// Treasury share of a liquidation `bonus`, rounded down.
+    pub fn treasury_fee(&self, bonus: u64) -> Result<u64> {
+        Ok((bonus as u128)
+            .safe_mul(self.treasury_fee_bps.into())?
+            .safe_div(MAX_BASIS_POINTS.into())? as u64)
+    }
+
+    /// Adds collateral and debt of a position to system totals.
+    pub fn increase_totals(&mut self, collateral: u64, debt: u64) -> Result<()> {
+        self.total_collateral = self.total_collateral.safe_add(collateral)?;
+        self.total_debt = self.total_debt.safe_add(debt)?;
+
+        Ok(())
+    }
+
+    /// Removes collateral and debt of a position from system totals.
+    pub fn decrease_totals(&mut self, collateral: u64, debt: u64) -> Result<()> {
+        // vaults can receive lamports outside the program, so their balance may exceed the total
+        self.total_collateral = self.total_collateral.saturating_sub(collateral);
+        self.total_debt = self.total_debt.safe_sub(debt)?;
+
+        Ok(())
+    }
+
+    /// Aggregate collateral ratio of all positions at `price`.
+    pub fn system_collateral_ratio(&self, price: Wad) -> Result<Wad> {
+        calculate_health_factor(self.total_collateral, self.total_debt, price)
+    }
+
+    /// Whether the system collateral ratio at `price` is below the critical ratio.
+    pub fn is_recovery_mode(&self, price: Wad) -> Result<bool> {
+        Ok(self.critical_collateral_ratio_bps > 0
+            && self.system_collateral_ratio(price)?
+                < Wad::from_bps(self.critical_collateral_ratio_bps))
+    }
+
+    /// Liquidation threshold, raised to the critical ratio in recovery mode.
+    pub fn effective_liquidation_threshold_bps(&self, recovery_mode: bool) -> u16 {
+        if recovery_mode {
+            self.liquidation_threshold_bps
+                .max(self.critical_collateral_ratio_bps)
+        } else {
+            self.liquidation_threshold_bps
+        }
+    }
+
+    /// Minimum LTV for minting, raised to the critical ratio in recovery mode.
+    pub fn effective_min_loan_to_value_bps(&self, recovery_mode: bool) -> u16 {
+        if recovery_mode {
+            self.min_loan_to_value_bps
+                .max(self.critical_collateral_ratio_bps)
+        } else {
+            self.min_loan_to_value_bps
+        }
+    }
+
+    /// Total redemption fee at `now`, capped at 100%.
+    pub fn redemption_fee_bps(&self, now: i64) -> Result<u16> {
+        Ok(self
+            .redemption_fee_bps
+            .saturating_add(self.decayed_redemption_base_rate_bps(now)?)
+            .min(MAX_BASIS_POINTS))
+    }
 }
@@ -1,5 +1,23 @@
+pub mod auction;
+pub use auction::*;
+
+pub mod blocklist_entry;
+pub use blocklist_entry::*;
+
 pub mod config;
 pub use config::*;
 
 pub mod position;
 pub use position::*;
+
+pub mod psm;
+pub use psm::*;
+
+pub mod savings;
+pub use savings::*;
+
+pub mod stability_pool;
+pub use stability_pool::*;
+
+pub mod treasury;
+pub use treasury::*;
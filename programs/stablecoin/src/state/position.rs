@@ -1,14 +1,62 @@
 use anchor_lang::prelude::*;
 
+use crate::{SafeMath, POSITION_VERSION};
+
 /// Represents a collateral debt position of a depositor.
 #[account]
 #[derive(InitSpace)]
 pub struct Position {
+    /// Layout version of the account, upgraded by `migrate_position`.
+    pub version: u8,
     /// Address of the depositor.
     pub depositor: Pubkey,
     pub amount_minted: u64,
+    /// Unix timestamp at which the auction of position collateral ends, 0 if never auctioned.
+    pub auction_ends_at: i64,
     /// Bump used for seed derivation.
     pub bump: u8,
     /// Bump used for vault system account seed derivation.
     pub vault_bump: u8,
+    /// Lamports moved from the vault into the position stake account, 0 if not staked.
+    pub staked_collateral: u64,
+    /// Bump used for stake account seed derivation.
+    pub stake_bump: u8,
+    /// Reserved for fields added in later layout versions.
+    pub reserved: [u8; 55],
+}
+
+/// `Position` layout before versioning was introduced, as written by the initial release.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct PositionV0 {
+    pub depositor: Pubkey,
+    pub amount_minted: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl From<PositionV0> for Position {
+    fn from(position: PositionV0) -> Self {
+        Self {
+            version: POSITION_VERSION,
+            depositor: position.depositor,
+            amount_minted: position.amount_minted,
+            auction_ends_at: 0,
+            bump: position.bump,
+            vault_bump: position.vault_bump,
+            staked_collateral: 0,
+            stake_bump: 0,
+            reserved: [0; 55],
+        }
+    }
+}
+
+impl Position {
+    pub fn is_in_auction(&self, now: i64) -> bool {
+        now < self.auction_ends_at
+    }
+
+    /// Collateral backing the position, counting staked lamports alongside the vault balance.
+    pub fn collateral(&self, vault_lamports: u64) -> Result<u64> {
+        vault_lamports.safe_add(self.staked_collateral)
+    }
 }
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Peg stability module swapping a configured stable token 1:1 with the stablecoin.
+#[account]
+#[derive(InitSpace)]
+pub struct Psm {
+    /// Mint of the stable token held in reserve.
+    pub stable_mint: Pubkey,
+    /// Fee charged when swapping stable token into stablecoin, in basis points.
+    pub mint_fee_bps: u16,
+    /// Fee charged when swapping stablecoin into stable token, in basis points.
+    pub redeem_fee_bps: u16,
+    /// Maximum stablecoin that can be outstanding through the PSM, in base units.
+    pub debt_ceiling: u64,
+    /// Stablecoin outstanding through the PSM, in base units.
+    pub debt: u64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
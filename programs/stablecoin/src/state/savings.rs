@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, SafeMath, MAX_BASIS_POINTS, SECONDS_PER_YEAR, WAD};
+
+/// Vault of deposited stablecoin earning the savings rate.
+///
+/// Deposits are tracked as shares whose value `chi` grows with every drip, so interest
+/// compounds each time it is dripped.
+#[account]
+#[derive(InitSpace)]
+pub struct Savings {
+    /// Total shares issued to deposits.
+    pub total_shares: u64,
+    /// Value of one share in stablecoin, scaled by `WAD`, starting at `WAD`.
+    pub chi: u128,
+    /// Unix timestamp of the last drip.
+    pub last_drip_at: i64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
+
+impl Savings {
+    /// Stablecoin value of `shares`, rounded down.
+    pub fn shares_to_amount(&self, shares: u64) -> Result<u64> {
+        Ok((shares as u128).safe_mul(self.chi)?.safe_div(WAD)? as u64)
+    }
+
+    /// Shares worth `amount` of stablecoin, rounded down.
+    pub fn amount_to_shares(&self, amount: u64) -> Result<u64> {
+        Ok((amount as u128).safe_mul(WAD)?.safe_div(self.chi)? as u64)
+    }
+
+    /// Shares worth `amount` of stablecoin, rounded up.
+    pub fn amount_to_shares_ceil(&self, amount: u64) -> Result<u64> {
+        Ok((amount as u128)
+            .safe_mul(WAD)?
+            .safe_add(self.chi.safe_sub(1)?)?
+            .safe_div(self.chi)? as u64)
+    }
+
+    /// Accrues interest at the savings rate since the last drip, capped at the config surplus so
+    /// the rate is never paid out of more than fee income. Returns the interest to mint into the
+    /// savings token account.
+    pub fn drip(&mut self, config: &mut Config, now: i64) -> Result<u64> {
+        let elapsed = now.safe_sub(self.last_drip_at)?.max(0);
+
+        if self.total_shares == 0 || config.savings_rate_bps == 0 || config.surplus == 0 {
+            self.last_drip_at = now;
+
+            return Ok(0);
+        }
+
+        let total_amount = self.shares_to_amount(self.total_shares)?;
+        let interest = (total_amount as u128)
+            .safe_mul(config.savings_rate_bps.into())?
+            .safe_mul(elapsed as u128)?
+            .safe_div((MAX_BASIS_POINTS as u128).safe_mul(SECONDS_PER_YEAR as u128)?)?;
+
+        // interest keeps accruing until frequent drips are large enough to round above 0
+        if interest == 0 {
+            return Ok(0);
+        }
+
+        let interest = interest.min(config.surplus.into()) as u64;
+
+        self.last_drip_at = now;
+
+        // chi is rounded down, so minted interest always covers the value of all shares
+        self.chi = self.chi.safe_add(
+            (interest as u128)
+                .safe_mul(WAD)?
+                .safe_div(self.total_shares.into())?,
+        )?;
+        config.surplus = config.surplus.safe_sub(interest)?;
+
+        Ok(interest)
+    }
+}
+
+/// Stablecoin deposit of a depositor in the savings vault.
+#[account]
+#[derive(InitSpace)]
+pub struct SavingsDeposit {
+    /// Address of the depositor.
+    pub depositor: Pubkey,
+    /// Shares held by the depositor.
+    pub shares: u64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StablecoinError, SafeMath, SafeMathAssign, SafeMulDiv, STABILITY_POOL_PRECISION,
+    STABILITY_POOL_SCALE_FACTOR,
+};
+
+/// Pool of deposited stablecoin used to absorb liquidated debt.
+///
+/// Tracks a running product `p` of deposit depletion and a running sum `s` of
+/// collateral gains, so each deposit can be settled from its own snapshot.
+///
+/// As in Liquity, `p` is multiplied by `STABILITY_POOL_SCALE_FACTOR` whenever it
+/// would drop below it, starting a new scale with its own sum, and reset when
+/// the pool is fully depleted, starting a new epoch in which earlier deposits
+/// are worth nothing.
+#[account]
+#[derive(InitSpace)]
+pub struct StabilityPool {
+    /// Total stablecoin deposited in the pool, in base units.
+    pub total_deposits: u64,
+    /// Running product of deposit depletion, starting at `STABILITY_POOL_PRECISION`.
+    pub p: u128,
+    /// Running sum of collateral gains per unit deposited in the current scale, scaled by `p`.
+    pub s: u128,
+    /// Number of times the pool was fully depleted.
+    pub epoch: u64,
+    /// Number of scales started across all epochs, indexing `scale_sums`.
+    pub scale: u64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+    /// Final sums of past scales, grown by the liquidation starting a new scale.
+    #[max_len(0)]
+    pub scale_sums: Vec<ScaleSum>,
+}
+
+/// Final running sum of a past scale.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct ScaleSum {
+    /// Epoch the scale belongs to.
+    pub epoch: u64,
+    /// Running sum when the scale ended.
+    pub sum: u128,
+}
+
+impl StabilityPool {
+    /// Account size holding `scale_sums` past scales.
+    pub fn space(scale_sums: usize) -> usize {
+        Self::DISCRIMINATOR.len() + Self::INIT_SPACE + scale_sums * ScaleSum::INIT_SPACE
+    }
+
+    /// Burns `debt` from pool deposits in exchange for `collateral` lamports.
+    pub fn offset(&mut self, debt: u64, collateral: u64) -> Result<()> {
+        require!(
+            self.total_deposits > 0 && self.total_deposits >= debt,
+            StablecoinError::InsufficientStabilityPoolDeposits
+        );
+
+        let total_deposits = self.total_deposits as u128;
+
+        self.s = self
+            .s
+            .safe_add((collateral as u128).mul_div_floor(self.p, total_deposits)?)?;
+
+        if debt == self.total_deposits {
+            self.next_scale()?;
+            self.epoch.safe_add_assign(1)?;
+            self.p = STABILITY_POOL_PRECISION;
+        } else {
+            // p >= STABILITY_POOL_SCALE_FACTOR, so the product fits and is non-zero
+            let mut product = self.p.safe_mul(total_deposits.safe_sub(debt.into())?)?;
+
+            while product.safe_div(total_deposits)? < STABILITY_POOL_SCALE_FACTOR {
+                // product < STABILITY_POOL_SCALE_FACTOR * u64::MAX, so scaling it cannot overflow
+                product = product.safe_mul(STABILITY_POOL_SCALE_FACTOR)?;
+                self.next_scale()?;
+            }
+
+            self.p = product.safe_div(total_deposits)?;
+        }
+
+        self.total_deposits = self.total_deposits.safe_sub(debt)?;
+
+        Ok(())
+    }
+
+    /// Final running sum of `scale`, or the current sum for the current scale, if it belongs to
+    /// `epoch`.
+    pub fn sum_at(&self, epoch: u64, scale: u64) -> Option<u128> {
+        let scale_sum = if scale == self.scale {
+            ScaleSum {
+                epoch: self.epoch,
+                sum: self.s,
+            }
+        } else {
+            *self.scale_sums.get(usize::try_from(scale).ok()?)?
+        };
+
+        (scale_sum.epoch == epoch).then_some(scale_sum.sum)
+    }
+
+    fn next_scale(&mut self) -> Result<()> {
+        self.scale_sums.push(ScaleSum {
+            epoch: self.epoch,
+            sum: self.s,
+        });
+        self.scale.safe_add_assign(1)?;
+        self.s = 0;
+
+        Ok(())
+    }
+}
+
+/// Stablecoin deposit of a depositor in the stability pool.
+#[account]
+#[derive(InitSpace)]
+pub struct StabilityDeposit {
+    /// Address of the depositor.
+    pub depositor: Pubkey,
+    /// Deposit at the time of the last snapshot, in base units.
+    pub amount: u64,
+    /// Pool product at the time of the last snapshot.
+    pub snapshot_p: u128,
+    /// Pool sum at the time of the last snapshot.
+    pub snapshot_s: u128,
+    /// Pool epoch at the time of the last snapshot.
+    pub snapshot_epoch: u64,
+    /// Pool scale at the time of the last snapshot.
+    pub snapshot_scale: u64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
+
+impl StabilityDeposit {
+    /// Deposit remaining after pool liquidations since the last snapshot, rounded down.
+    ///
+    /// Deposits from an earlier epoch were fully used, and deposits two or more scales behind
+    /// were depleted below one billionth and are treated as fully used.
+    pub fn compounded_amount(&self, pool: &StabilityPool) -> Result<u64> {
+        if self.amount == 0 || self.snapshot_epoch != pool.epoch {
+            return Ok(0);
+        }
+
+        let compounded_amount = match pool.scale.safe_sub(self.snapshot_scale)? {
+            0 => (self.amount as u128).mul_div_floor(pool.p, self.snapshot_p)?,
+            1 => (self.amount as u128).mul_div_floor(
+                pool.p,
+                self.snapshot_p.safe_mul(STABILITY_POOL_SCALE_FACTOR)?,
+            )?,
+            _ => 0,
+        };
+
+        to_u64(compounded_amount)
+    }
+
+    /// Collateral earned from pool liquidations since the last snapshot, rounded down.
+    ///
+    /// Gains are earned in the snapshot scale and the one after it, later scales only apply to
+    /// deposits already depleted below one billionth.
+    pub fn collateral_gain(&self, pool: &StabilityPool) -> Result<u64> {
+        if self.amount == 0 {
+            return Ok(0);
+        }
+
+        let first_portion = pool
+            .sum_at(self.snapshot_epoch, self.snapshot_scale)
+            .ok_or(StablecoinError::MathOverflow)?
+            .safe_sub(self.snapshot_s)?;
+        let second_portion = pool
+            .sum_at(self.snapshot_epoch, self.snapshot_scale.safe_add(1)?)
+            .unwrap_or_default()
+            .safe_div(STABILITY_POOL_SCALE_FACTOR)?;
+
+        to_u64(
+            (self.amount as u128)
+                .mul_div_floor(first_portion.safe_add(second_portion)?, self.snapshot_p)?,
+        )
+    }
+
+    /// Settles deposit to `amount` at the current pool snapshot.
+    pub fn snapshot(&mut self, pool: &StabilityPool, amount: u64) {
+        self.amount = amount;
+        self.snapshot_p = pool.p;
+        self.snapshot_s = pool.s;
+        self.snapshot_epoch = pool.epoch;
+        self.snapshot_scale = pool.scale;
+    }
+}
+
+fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| StablecoinError::ConversionFailed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_pool() -> StabilityPool {
+        StabilityPool {
+            total_deposits: 0,
+            p: STABILITY_POOL_PRECISION,
+            s: 0,
+            epoch: 0,
+            scale: 0,
+            bump: 0,
+            scale_sums: vec![],
+        }
+    }
+
+    fn deposit(pool: &mut StabilityPool, amount: u64) -> StabilityDeposit {
+        let mut stability_deposit = StabilityDeposit {
+            depositor: Pubkey::default(),
+            amount: 0,
+            snapshot_p: 0,
+            snapshot_s: 0,
+            snapshot_epoch: 0,
+            snapshot_scale: 0,
+            bump: 0,
+        };
+
+        stability_deposit.snapshot(pool, amount);
+        pool.total_deposits += amount;
+
+        stability_deposit
+    }
+
+    #[test]
+    fn offset_rescales_product_below_scale_factor() {
+        let mut pool = new_pool();
+        let depositor = deposit(&mut pool, 1_000_000_000_000);
+
+        pool.offset(999_999_999_999, 1_000_000_000).unwrap();
+
+        assert_eq!(pool.scale, 1);
+        assert_eq!(pool.p, 1_000_000_000_000_000);
+        assert_eq!(depositor.compounded_amount(&pool).unwrap(), 1);
+        assert_eq!(depositor.collateral_gain(&pool).unwrap(), 1_000_000_000);
+    }
+
+    #[test]
+    fn repeated_near_total_offsets() {
+        let mut pool = new_pool();
+        let mut depositors = vec![];
+        let collateral = 1_000_000_000;
+
+        // each round a new deposit is offset down to a single base unit, which would drive an
+        // unscaled product to zero by the second round
+        for round in 1..=20u64 {
+            let depositor = deposit(&mut pool, 1_000_000_000_000);
+
+            pool.offset(pool.total_deposits - 1, collateral).unwrap();
+
+            assert!(pool.p >= STABILITY_POOL_SCALE_FACTOR && pool.p <= STABILITY_POOL_PRECISION);
+            assert_eq!(pool.total_deposits, 1);
+
+            // newest deposit held all but one billionth of the pool
+            let gain = depositor.collateral_gain(&pool).unwrap();
+            assert!((collateral - 2..=collateral).contains(&gain));
+
+            depositors.push(depositor);
+
+            let total_compounded = depositors
+                .iter()
+                .map(|depositor| depositor.compounded_amount(&pool).unwrap())
+                .sum::<u64>();
+            let total_gains = depositors
+                .iter()
+                .map(|depositor| depositor.collateral_gain(&pool).unwrap())
+                .sum::<u64>();
+
+            assert!(total_compounded <= pool.total_deposits);
+            assert!(total_gains <= round * collateral);
+            assert!(total_gains >= round * (collateral - 2));
+        }
+    }
+
+    #[test]
+    fn full_offset_starts_new_epoch() {
+        let mut pool = new_pool();
+        let first = deposit(&mut pool, 1_000_000);
+
+        pool.offset(1_000_000, 500_000_000).unwrap();
+
+        assert_eq!(pool.epoch, 1);
+        assert_eq!(pool.p, STABILITY_POOL_PRECISION);
+        assert_eq!(pool.total_deposits, 0);
+        assert_eq!(first.compounded_amount(&pool).unwrap(), 0);
+        assert_eq!(first.collateral_gain(&pool).unwrap(), 500_000_000);
+
+        let second = deposit(&mut pool, 1_000_000);
+
+        pool.offset(500_000, 100_000_000).unwrap();
+
+        assert_eq!(second.compounded_amount(&pool).unwrap(), 500_000);
+        assert_eq!(second.collateral_gain(&pool).unwrap(), 100_000_000);
+        // gains of the depleted epoch are not diluted by later deposits
+        assert_eq!(first.compounded_amount(&pool).unwrap(), 0);
+        assert_eq!(first.collateral_gain(&pool).unwrap(), 500_000_000);
+    }
+
+    #[test]
+    fn offset_above_deposits_fails() {
+        let mut pool = new_pool();
+
+        assert!(pool.offset(0, 0).is_err());
+
+        deposit(&mut pool, 1_000_000);
+
+        assert!(pool.offset(1_000_001, 0).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::StablecoinError, SafeMath};
+
+/// Treasury accumulating protocol revenue in lamports held by this account and stablecoin held
+/// by its associated token account.
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Epoch of the last withdrawal.
+    pub epoch: u64,
+    /// Lamports withdrawn during `epoch`.
+    pub sol_withdrawn: u64,
+    /// Stablecoin withdrawn during `epoch`, in base units.
+    pub token_withdrawn: u64,
+    /// Bump used for seed derivation.
+    pub bump: u8,
+}
+
+impl Treasury {
+    /// Records a withdrawal of `lamports` and `amount` of stablecoin in `epoch`, checking that
+    /// the epoch's totals stay within the given limits.
+    pub fn record_withdrawal(
+        &mut self,
+        epoch: u64,
+        lamports: u64,
+        amount: u64,
+        sol_limit: u64,
+        token_limit: u64,
+    ) -> Result<()> {
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.sol_withdrawn = 0;
+            self.token_withdrawn = 0;
+        }
+
+        self.sol_withdrawn = self.sol_withdrawn.safe_add(lamports)?;
+        self.token_withdrawn = self.token_withdrawn.safe_add(amount)?;
+
+        require_gte!(
+            sol_limit,
+            self.sol_withdrawn,
+            StablecoinError::TreasuryWithdrawalLimitExceeded
+        );
+        require_gte!(
+            token_limit,
+            self.token_withdrawn,
+            StablecoinError::TreasuryWithdrawalLimitExceeded
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use std::fmt;
+use switchboard_on_demand::prelude::rust_decimal::Decimal;
+
+use crate::{error::StablecoinError, SafeMath, SafeMulDiv, SafePow, MAX_BASIS_POINTS, WAD};
+
+const WAD_DECIMALS: u32 = 18;
+
+/// Unsigned fixed-point number with 18 decimals.
+///
+/// `checked_mul` and `checked_div` round down, `_ceil` variants round up. Callers pick the
+/// direction that rounds against the user.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Wad(pub u128);
+
+impl Wad {
+    pub const ZERO: Wad = Wad(0);
+    pub const ONE: Wad = Wad(WAD);
+    pub const MAX: Wad = Wad(u128::MAX);
+
+    pub fn from_bps(bps: u16) -> Wad {
+        Wad(bps as u128 * (WAD / MAX_BASIS_POINTS as u128))
+    }
+
+    /// Converts `amount` with `decimals` into a fixed-point number.
+    pub fn from_amount(amount: u64, decimals: u8) -> Result<Wad> {
+        Ok(Wad((amount as u128).safe_mul(scale(decimals)?)?))
+    }
+
+    /// Converts a non-negative decimal, rounding down past 18 decimals.
+    pub fn from_decimal(value: Decimal) -> Result<Wad> {
+        require!(!value.is_sign_negative(), StablecoinError::ConversionFailed);
+
+        let mantissa = value.mantissa() as u128;
+        let scale = value.scale();
+
+        let value = if scale > WAD_DECIMALS {
+            mantissa.safe_div(10u128.safe_pow(scale - WAD_DECIMALS)?)?
+        } else {
+            mantissa.safe_mul(10u128.safe_pow(WAD_DECIMALS - scale)?)?
+        };
+
+        Ok(Wad(value))
+    }
+
+    /// Converts into an amount with `decimals`, rounding down.
+    pub fn to_amount_floor(self, decimals: u8) -> Result<u64> {
+        to_u64(self.0.safe_div(scale(decimals)?)?)
+    }
+
+    /// Converts into an amount with `decimals`, rounding up.
+    pub fn to_amount_ceil(self, decimals: u8) -> Result<u64> {
+        to_u64(self.0.mul_div_ceil(1, scale(decimals)?)?)
+    }
+
+    pub fn checked_add(self, rhs: Wad) -> Option<Wad> {
+        self.0.checked_add(rhs.0).map(Wad)
+    }
+
+    pub fn checked_sub(self, rhs: Wad) -> Option<Wad> {
+        self.0.checked_sub(rhs.0).map(Wad)
+    }
+
+    pub fn checked_mul(self, rhs: Wad) -> Option<Wad> {
+        self.0.mul_div_floor(rhs.0, WAD).ok().map(Wad)
+    }
+
+    pub fn checked_div(self, rhs: Wad) -> Option<Wad> {
+        self.0.mul_div_floor(WAD, rhs.0).ok().map(Wad)
+    }
+
+    pub fn mul_ceil(self, rhs: Wad) -> Result<Wad> {
+        Ok(Wad(self.0.mul_div_ceil(rhs.0, WAD)?))
+    }
+
+    pub fn div_ceil(self, rhs: Wad) -> Result<Wad> {
+        Ok(Wad(self.0.mul_div_ceil(WAD, rhs.0)?))
+    }
+}
+
+impl fmt::Display for Wad {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:018}", self.0 / WAD, self.0 % WAD)
+    }
+}
+
+/// Factor converting an amount with `decimals` into a fixed-point number.
+fn scale(decimals: u8) -> Result<u128> {
+    10u128.safe_pow(WAD_DECIMALS.safe_sub(decimals.into())?)
+}
+
+fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| StablecoinError::ConversionFailed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_decimal_scales_to_wad() {
+        assert_eq!(
+            Wad::from_decimal(Decimal::new(15025, 2)).unwrap(),
+            Wad(150_250_000_000_000_000_000)
+        );
+        assert_eq!(Wad::from_decimal(Decimal::ZERO).unwrap(), Wad::ZERO);
+    }
+
+    #[test]
+    fn from_decimal_rounds_down_past_wad_decimals() {
+        // 0.0000000000000000019 has 19 decimals
+        let value = Decimal::new(19, 19);
+
+        assert_eq!(Wad::from_decimal(value).unwrap(), Wad(1));
+    }
+
+    #[test]
+    fn from_decimal_rejects_negative() {
+        assert!(Wad::from_decimal(Decimal::new(-1, 0)).is_err());
+    }
+
+    #[test]
+    fn from_bps_scales_to_wad() {
+        assert_eq!(Wad::from_bps(0), Wad::ZERO);
+        assert_eq!(Wad::from_bps(1), Wad(WAD / 10_000));
+        assert_eq!(Wad::from_bps(MAX_BASIS_POINTS), Wad::ONE);
+        assert_eq!(Wad::from_bps(12_500), Wad(WAD * 5 / 4));
+    }
+
+    #[test]
+    fn to_amount_rounds_in_requested_direction() {
+        // 1.5 units of the smallest denomination
+        let value = Wad(15 * 10u128.pow(11));
+
+        assert_eq!(value.to_amount_floor(6).unwrap(), 1);
+        assert_eq!(value.to_amount_ceil(6).unwrap(), 2);
+    }
+
+    #[test]
+    fn to_amount_exact_value_is_not_rounded_up() {
+        let value = Wad::from_amount(1_234_567, 6).unwrap();
+
+        assert_eq!(value.to_amount_floor(6).unwrap(), 1_234_567);
+        assert_eq!(value.to_amount_ceil(6).unwrap(), 1_234_567);
+    }
+
+    #[test]
+    fn to_amount_ceil_of_smallest_remainder() {
+        assert_eq!(Wad(1).to_amount_floor(9).unwrap(), 0);
+        assert_eq!(Wad(1).to_amount_ceil(9).unwrap(), 1);
+    }
+
+    #[test]
+    fn to_amount_fails_above_u64() {
+        let value = Wad::from_amount(u64::MAX, 6).unwrap();
+
+        assert!(value
+            .checked_add(Wad(10u128.pow(12)))
+            .unwrap()
+            .to_amount_floor(6)
+            .is_err());
+    }
+}
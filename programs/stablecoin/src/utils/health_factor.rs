@@ -1,25 +1,101 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::spl_token::native_mint;
-use switchboard_on_demand::prelude::rust_decimal::Decimal;
 
-use crate::{SafeMath, MINT_DECIMALS};
+use crate::{SafeMath, SafeMulDiv, Wad, MAX_BASIS_POINTS, MINT_DECIMALS, WAD};
 
 /// Calculates the health factor given the collateral amount, minted amount,
 ///
 /// Health factor of 1.0 means 1 unit of collateral (SOL) backs 1 unit of debt (stablecoin).
-pub fn calculate_health_factor(
+/// Rounds down, against the depositor.
+pub fn calculate_health_factor(lamports: u64, amount_minted: u64, price: Wad) -> Result<Wad> {
+    if amount_minted == 0 {
+        return Ok(Wad::MAX);
+    }
+
+    let collateral = Wad::from_amount(lamports, native_mint::DECIMALS)?;
+    let usd_minted = Wad::from_amount(amount_minted, MINT_DECIMALS)?;
+    // single rounding step, equivalent to collateral * price / usd_minted
+    let health_factor = collateral.0.mul_div_floor(price.0, usd_minted.0)?;
+
+    Ok(Wad(health_factor))
+}
+
+/// Calculates the lamports paid out for `amount` of debt at `price` plus `bonus_bps`, rounded
+/// down so the protocol never pays out more collateral than owed.
+pub fn calculate_collateral_for_debt(amount: u64, price: Wad, bonus_bps: u16) -> Result<u64> {
+    let usd_amount = Wad::from_amount(amount, MINT_DECIMALS)?;
+    let lamports = usd_amount.0.mul_div_floor(
+        WAD.safe_mul(MAX_BASIS_POINTS.safe_add(bonus_bps)?.into())?,
+        price.0.safe_mul(MAX_BASIS_POINTS.into())?,
+    )?;
+
+    Wad(lamports).to_amount_floor(native_mint::DECIMALS)
+}
+
+/// Calculates the value of `lamports` in stablecoin base units, rounded down.
+pub fn calculate_collateral_value(lamports: u64, price: Wad) -> Result<u64> {
+    Wad::from_amount(lamports, native_mint::DECIMALS)?
+        .safe_mul(price)?
+        .to_amount_floor(MINT_DECIMALS)
+}
+
+/// Calculates the additional stablecoin that can be minted while staying at or above `min_ltv`.
+pub fn calculate_max_mintable(
     lamports: u64,
     amount_minted: u64,
-    price: Decimal,
-) -> Result<Decimal> {
-    if amount_minted == 0 {
-        return Ok(Decimal::MAX);
+    price: Wad,
+    min_ltv: Wad,
+) -> Result<u64> {
+    let max_debt = Wad::from_amount(lamports, native_mint::DECIMALS)?
+        .safe_mul(price)?
+        .safe_div(min_ltv)?
+        .to_amount_floor(MINT_DECIMALS)?;
+
+    Ok(max_debt.saturating_sub(amount_minted))
+}
+
+/// Calculates the lamports that can be withdrawn while staying at or above `min_ltv` and keeping
+/// `min_rent` in the vault.
+pub fn calculate_max_withdrawable(
+    lamports: u64,
+    amount_minted: u64,
+    price: Wad,
+    min_ltv: Wad,
+    min_rent: u64,
+) -> Result<u64> {
+    let required_lamports = Wad::from_amount(amount_minted, MINT_DECIMALS)?
+        .mul_ceil(min_ltv)?
+        .div_ceil(price)?
+        .to_amount_ceil(native_mint::DECIMALS)?;
+
+    Ok(lamports.saturating_sub(required_lamports.max(min_rent)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_factor_rounds_down() {
+        let health_factor = calculate_health_factor(1, 3, Wad::ONE).unwrap();
+
+        assert_eq!(health_factor, Wad(333_333_333_333_333));
+    }
+
+    #[test]
+    fn collateral_for_dust_debt_rounds_down() {
+        // $0.000001 at $150 with 2.5% bonus is 6.83 lamports
+        let lamports = calculate_collateral_for_debt(1, Wad(150 * WAD), 250).unwrap();
+
+        assert_eq!(lamports, 6);
     }
 
-    let collateral_value =
-        Decimal::new(lamports as i64, native_mint::DECIMALS as u32).safe_mul(price)?;
-    let usd_minted = Decimal::new(amount_minted as i64, MINT_DECIMALS as u32);
-    let health_factor = collateral_value.safe_div(usd_minted)?;
+    #[test]
+    fn max_withdrawable_rounds_required_collateral_up() {
+        // $0.000001 at 150% LTV and $7 requires 214.29 lamports
+        let lamports =
+            calculate_max_withdrawable(1_000, 1, Wad(7 * WAD), Wad::from_bps(15000), 0).unwrap();
 
-    Ok(health_factor)
+        assert_eq!(lamports, 785);
+    }
 }
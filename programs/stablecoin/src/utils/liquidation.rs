@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    calculate_collateral_for_debt, calculate_health_factor, error::StablecoinError,
+    validate_above_min_health_factor, Config, SafeMath, Wad,
+};
+
+/// Result of burning debt against a position's collateral in `liquidate_position`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Liquidation {
+    /// Lamports taken from the vault, including the bonus.
+    pub collateral_seized: u64,
+    /// Part of `collateral_seized` paid to the treasury rather than the liquidator.
+    pub treasury_fee: u64,
+    /// Vault lamports left after seizing, 0 if the vault cannot cover `collateral_seized`.
+    pub remaining_collateral: u64,
+    /// Debt left on the position.
+    pub remaining_debt: u64,
+}
+
+/// Checks that a position with `lamports` of collateral and `amount_minted` of debt can be
+/// liquidated at `price`, and that burning `amount_to_burn` restores it to the minimum LTV.
+pub fn calculate_liquidation(
+    config: &Config,
+    lamports: u64,
+    amount_minted: u64,
+    amount_to_burn: u64,
+    price: Wad,
+) -> Result<Liquidation> {
+    let health_factor = calculate_health_factor(lamports, amount_minted, price)?;
+    let recovery_mode = config.is_recovery_mode(price)?;
+
+    require_gt!(
+        Wad::from_bps(config.effective_liquidation_threshold_bps(recovery_mode)),
+        health_factor,
+        StablecoinError::AboveLiquidationThreshold
+    );
+
+    let collateral_seized =
+        calculate_collateral_for_debt(amount_to_burn, price, config.liquidation_bonus_bps)?;
+    let bonus =
+        collateral_seized.safe_sub(calculate_collateral_for_debt(amount_to_burn, price, 0)?)?;
+    let treasury_fee = config.treasury_fee(bonus)?;
+
+    // vault balance is the max amount that can be liquidated
+    let remaining_collateral = lamports.saturating_sub(collateral_seized);
+    let remaining_debt = amount_minted
+        .safe_sub(amount_to_burn)
+        .map_err(|_| StablecoinError::InsufficientAmountMinted)?;
+
+    validate_above_min_health_factor(
+        calculate_health_factor(remaining_collateral, remaining_debt, price)?,
+        Wad::from_bps(config.min_loan_to_value_bps),
+    )?;
+
+    Ok(Liquidation {
+        collateral_seized,
+        treasury_fee,
+        remaining_collateral,
+        remaining_debt,
+    })
+}
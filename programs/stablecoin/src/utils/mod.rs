@@ -1,16 +1,31 @@
+pub mod fixed_point;
+pub use fixed_point::*;
+
 pub mod health_factor;
 pub use health_factor::*;
 
+pub mod liquidation;
+pub use liquidation::*;
+
+pub mod oracle;
+pub use oracle::*;
+
 pub mod safe_math;
 pub use safe_math::*;
 
 pub mod signer_seeds;
 
+pub mod stake;
+pub use stake::*;
+
 pub mod switchboard;
 pub use switchboard::*;
 
 pub mod system;
 pub use system::*;
 
+pub mod token;
+pub use token::*;
+
 pub mod validate;
 pub use validate::*;
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::get_slot;
+#[cfg(not(feature = "mock-oracle"))]
+use switchboard_on_demand::{default_queue, SwitchboardQuote, SwitchboardQuoteExt};
+
+use crate::Wad;
+#[cfg(feature = "mock-oracle")]
+use crate::{error::StablecoinError, ORACLE_MAX_AGE, SOL_USD_FEED_ID};
+#[cfg(not(feature = "mock-oracle"))]
+use crate::{get_oracle_quote, get_price_from_quote};
+
+/// Account holding the SOL/USD quote.
+#[cfg(not(feature = "mock-oracle"))]
+pub type OracleQuoteAccount = SwitchboardQuote;
+
+/// Account holding the SOL/USD quote, settable by tests under the `mock-oracle` feature.
+#[cfg(feature = "mock-oracle")]
+pub type OracleQuoteAccount = mock_oracle::PriceFeed;
+
+/// Address the SOL/USD quote account is expected at.
+#[cfg(not(feature = "mock-oracle"))]
+pub fn oracle_quote_address(oracle_quote: &Account<OracleQuoteAccount>) -> Pubkey {
+    oracle_quote.canonical_key(&default_queue())
+}
+
+/// Address the SOL/USD quote account is expected at.
+#[cfg(feature = "mock-oracle")]
+pub fn oracle_quote_address(oracle_quote: &Account<OracleQuoteAccount>) -> Pubkey {
+    Pubkey::create_program_address(
+        &[
+            mock_oracle::PRICE_FEED_SEED,
+            SOL_USD_FEED_ID.as_ref(),
+            &[oracle_quote.bump],
+        ],
+        &mock_oracle::ID,
+    )
+    .unwrap_or_default()
+}
+
+/// Reads the SOL/USD price from a verified Switchboard quote.
+#[cfg(not(feature = "mock-oracle"))]
+pub fn get_oracle_price<'info>(
+    oracle_queue: &impl ToAccountInfo<'info>,
+    oracle_quote: &Account<'info, OracleQuoteAccount>,
+    slot_hashes_sysvar: &impl ToAccountInfo<'info>,
+    instructions_sysvar: &impl ToAccountInfo<'info>,
+    clock: &impl ToAccountInfo<'info>,
+) -> Result<Wad> {
+    let oracle_quote_data = oracle_quote.to_account_data().unwrap();
+
+    let quote = get_oracle_quote(
+        oracle_queue.to_account_info(),
+        slot_hashes_sysvar.to_account_info(),
+        instructions_sysvar.to_account_info(),
+        get_slot(clock.to_account_info()),
+        oracle_quote_data.as_slice(),
+    )?;
+
+    get_price_from_quote(quote)
+}
+
+/// Reads the SOL/USD price from a mock price feed, rejecting prices set more than
+/// `ORACLE_MAX_AGE` slots ago unless `no-staleness-check` is enabled. Confidence is not checked,
+/// as with Switchboard quotes.
+#[cfg(feature = "mock-oracle")]
+pub fn get_oracle_price<'info>(
+    _oracle_queue: &impl ToAccountInfo<'info>,
+    oracle_quote: &Account<'info, OracleQuoteAccount>,
+    _slot_hashes_sysvar: &impl ToAccountInfo<'info>,
+    _instructions_sysvar: &impl ToAccountInfo<'info>,
+    clock: &impl ToAccountInfo<'info>,
+) -> Result<Wad> {
+    require!(
+        oracle_quote.feed_id == SOL_USD_FEED_ID,
+        StablecoinError::MissingRequiredPriceFeed
+    );
+
+    if cfg!(not(feature = "no-staleness-check")) {
+        let age = get_slot(clock.to_account_info()).saturating_sub(oracle_quote.slot);
+
+        require!(age <= ORACLE_MAX_AGE as u64, StablecoinError::StalePrice);
+    }
+
+    // zero and negative prices are rejected by validate_price
+    Ok(Wad(u128::try_from(oracle_quote.price).unwrap_or_default()))
+}
@@ -1,8 +1,7 @@
 use anchor_lang::prelude::*;
 use std::panic::Location;
-use switchboard_on_demand::prelude::rust_decimal::Decimal;
 
-use crate::{error::StablecoinError, MAX_BASIS_POINTS};
+use crate::{error::StablecoinError, Wad};
 
 pub trait SafeMath: Sized {
     fn safe_add(self, rhs: Self) -> Result<Self>;
@@ -119,7 +118,7 @@ macro_rules! assign_impl {
 assign_impl!(u16);
 assign_impl!(u32);
 assign_impl!(u64);
-checked_impl!(Decimal);
+checked_impl!(Wad);
 
 pub trait SafePow: Sized {
     fn safe_pow(self, exp: u32) -> Result<Self>;
@@ -149,7 +148,183 @@ macro_rules! pow_impl {
 }
 
 pow_impl!(u64);
+pow_impl!(u128);
 
-pub fn bps_to_decimal(bps: u16) -> Result<Decimal> {
-    Decimal::from(bps).safe_div(MAX_BASIS_POINTS.into())
+/// Multiply-then-divide with explicit rounding direction. Protocol math rounds collateral and
+/// health factors down and debts up, so rounding never favours the user.
+pub trait SafeMulDiv: Sized {
+    /// `self * numerator / denominator`, rounded down.
+    fn mul_div_floor(self, numerator: Self, denominator: Self) -> Result<Self>;
+    /// `self * numerator / denominator`, rounded up.
+    fn mul_div_ceil(self, numerator: Self, denominator: Self) -> Result<Self>;
+}
+
+impl SafeMulDiv for u64 {
+    #[track_caller]
+    fn mul_div_floor(self, numerator: u64, denominator: u64) -> Result<u64> {
+        let result = (self as u128).mul_div_floor(numerator.into(), denominator.into())?;
+
+        u64::try_from(result).map_err(|_| StablecoinError::MathOverflow.into())
+    }
+
+    #[track_caller]
+    fn mul_div_ceil(self, numerator: u64, denominator: u64) -> Result<u64> {
+        let result = (self as u128).mul_div_ceil(numerator.into(), denominator.into())?;
+
+        u64::try_from(result).map_err(|_| StablecoinError::MathOverflow.into())
+    }
+}
+
+impl SafeMulDiv for u128 {
+    #[track_caller]
+    fn mul_div_floor(self, numerator: u128, denominator: u128) -> Result<u128> {
+        Ok(mul_div_rem(self, numerator, denominator)?.0)
+    }
+
+    #[track_caller]
+    fn mul_div_ceil(self, numerator: u128, denominator: u128) -> Result<u128> {
+        let (quotient, remainder) = mul_div_rem(self, numerator, denominator)?;
+
+        if remainder > 0 {
+            quotient.safe_add(1)
+        } else {
+            Ok(quotient)
+        }
+    }
+}
+
+/// Computes quotient and remainder of `a * b / c`, using a 256-bit product if `a * b` overflows.
+#[track_caller]
+fn mul_div_rem(a: u128, b: u128, c: u128) -> Result<(u128, u128)> {
+    if c == 0 {
+        let caller = Location::caller();
+        msg!("Division error at {}:{}", caller.file(), caller.line());
+        return Err(StablecoinError::MathOverflow.into());
+    }
+
+    if let Some(product) = a.checked_mul(b) {
+        return Ok((product / c, product % c));
+    }
+
+    let (hi, lo) = full_mul(a, b);
+
+    // quotient does not fit in 128 bits
+    if hi >= c {
+        let caller = Location::caller();
+        msg!("Math overflow at {}:{}", caller.file(), caller.line());
+        return Err(StablecoinError::MathOverflow.into());
+    }
+
+    Ok(div_rem_wide(hi, lo, c))
+}
+
+/// Multiplies into a 256-bit product split into high and low halves.
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_lo, a_hi) = (a & MASK, a >> 64);
+    let (b_lo, b_hi) = (b & MASK, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+/// Divides 256-bit `hi:lo` by `divisor` using shift-subtract, requires `hi < divisor`.
+fn div_rem_wide(hi: u128, lo: u128, divisor: u128) -> (u128, u128) {
+    let mut remainder = hi;
+    let mut quotient = 0u128;
+
+    for i in (0..u128::BITS).rev() {
+        let carry = remainder >> 127;
+
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_rounds_in_requested_direction() {
+        assert_eq!(7u64.mul_div_floor(2, 3).unwrap(), 4);
+        assert_eq!(7u64.mul_div_ceil(2, 3).unwrap(), 5);
+    }
+
+    #[test]
+    fn mul_div_exact_result_is_not_rounded_up() {
+        assert_eq!(6u64.mul_div_floor(2, 3).unwrap(), 4);
+        assert_eq!(6u64.mul_div_ceil(2, 3).unwrap(), 4);
+    }
+
+    #[test]
+    fn mul_div_zero_operand() {
+        assert_eq!(0u64.mul_div_floor(5, 3).unwrap(), 0);
+        assert_eq!(0u64.mul_div_ceil(5, 3).unwrap(), 0);
+        assert_eq!(5u64.mul_div_ceil(0, 3).unwrap(), 0);
+    }
+
+    #[test]
+    fn mul_div_by_zero_fails() {
+        assert!(1u64.mul_div_floor(1, 0).is_err());
+        assert!(1u128.mul_div_ceil(1, 0).is_err());
+    }
+
+    #[test]
+    fn mul_div_ceil_of_smallest_remainder() {
+        assert_eq!(1u64.mul_div_floor(1, u64::MAX).unwrap(), 0);
+        assert_eq!(1u64.mul_div_ceil(1, u64::MAX).unwrap(), 1);
+    }
+
+    #[test]
+    fn mul_div_u64_intermediate_exceeds_u64() {
+        assert_eq!(
+            u64::MAX.mul_div_floor(u64::MAX, u64::MAX).unwrap(),
+            u64::MAX
+        );
+        assert!(u64::MAX.mul_div_floor(2, 1).is_err());
+    }
+
+    #[test]
+    fn mul_div_u128_intermediate_exceeds_u128() {
+        assert_eq!(
+            u128::MAX.mul_div_floor(u128::MAX, u128::MAX).unwrap(),
+            u128::MAX
+        );
+        // (2^127 + 1) * 3 / 2 = 3 * 2^126 + 1.5
+        let a = (1u128 << 127) + 1;
+        assert_eq!(a.mul_div_floor(3, 2).unwrap(), 3 * (1u128 << 126) + 1);
+        assert_eq!(a.mul_div_ceil(3, 2).unwrap(), 3 * (1u128 << 126) + 2);
+    }
+
+    #[test]
+    fn mul_div_u128_quotient_overflow_fails() {
+        assert!(u128::MAX.mul_div_floor(2, 1).is_err());
+        assert!(u128::MAX.mul_div_ceil(u128::MAX, u128::MAX - 1).is_err());
+    }
+
+    #[test]
+    fn mul_div_u128_wide_exact_division() {
+        assert_eq!(
+            u128::MAX.mul_div_ceil(u128::MAX - 1, u128::MAX).unwrap(),
+            u128::MAX - 1
+        );
+    }
 }
@@ -18,3 +18,38 @@ macro_rules! vault_signer {
         &[VAULT_SEED, $position_key.as_ref(), &[$bump]]
     };
 }
+
+#[macro_export]
+macro_rules! stake_signer {
+    ($position_key: expr, $bump: expr) => {
+        &[STAKE_SEED, $position_key.as_ref(), &[$bump]]
+    };
+}
+
+#[macro_export]
+macro_rules! stability_pool_signer {
+    ($bump: expr) => {
+        &[STABILITY_POOL_SEED, &[$bump]]
+    };
+}
+
+#[macro_export]
+macro_rules! psm_signer {
+    ($stable_mint_key: expr, $bump: expr) => {
+        &[PSM_SEED, $stable_mint_key.as_ref(), &[$bump]]
+    };
+}
+
+#[macro_export]
+macro_rules! savings_signer {
+    ($bump: expr) => {
+        &[SAVINGS_SEED, &[$bump]]
+    };
+}
+
+#[macro_export]
+macro_rules! treasury_signer {
+    ($bump: expr) => {
+        &[TREASURY_SEED, &[$bump]]
+    };
+}
@@ -0,0 +1,92 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::invoke_signed,
+    system_program::{transfer, Transfer},
+};
+use solana_stake_interface::{
+    instruction::{authorize, split},
+    state::{StakeAuthorize, StakeStateV2},
+};
+
+use crate::{Position, SafeMathAssign};
+
+/// Accounts splitting staked collateral of a position off to a liquidator, bidder or redeemer.
+pub struct StakeSeizure<'a, 'info> {
+    /// Stake account of the position.
+    pub stake_account: &'a AccountInfo<'info>,
+    /// New account receiving the split stake, signing its creation.
+    pub seized_stake: &'a AccountInfo<'info>,
+    /// Vault of the position, staker and withdrawer of the position stake.
+    pub vault: &'a AccountInfo<'info>,
+    /// Pays rent of the seized stake account and becomes its staker and withdrawer.
+    pub recipient: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub stake_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl StakeSeizure<'_, '_> {
+    /// Moves `lamports` of staked collateral of `position` into the seized stake account and hands
+    /// it to the recipient. Seizing all staked collateral moves the whole stake, including rewards
+    /// accrued since it was staked.
+    pub fn seize(
+        &self,
+        position: &mut Position,
+        lamports: u64,
+        vault_signer: &[&[u8]],
+    ) -> Result<()> {
+        let split_lamports = if lamports == position.staked_collateral {
+            self.stake_account.lamports()
+        } else {
+            lamports
+        };
+
+        position.staked_collateral.safe_sub_assign(lamports)?;
+
+        // split destination must be rent exempt before it receives active stake
+        transfer(
+            CpiContext::new(
+                self.system_program.clone(),
+                Transfer {
+                    from: self.recipient.clone(),
+                    to: self.seized_stake.clone(),
+                },
+            ),
+            Rent::get()?.minimum_balance(StakeStateV2::size_of()),
+        )?;
+
+        let account_infos = [
+            self.stake_account.clone(),
+            self.seized_stake.clone(),
+            self.vault.clone(),
+            self.clock.clone(),
+            self.stake_program.clone(),
+            self.system_program.clone(),
+        ];
+
+        for instruction in split(
+            self.stake_account.key,
+            self.vault.key,
+            split_lamports,
+            self.seized_stake.key,
+        ) {
+            invoke_signed(&instruction, &account_infos, &[vault_signer])?;
+        }
+
+        for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+            invoke_signed(
+                &authorize(
+                    self.seized_stake.key,
+                    self.vault.key,
+                    self.recipient.key,
+                    stake_authorize,
+                    None,
+                ),
+                &account_infos,
+                &[vault_signer],
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,8 @@
 use anchor_lang::prelude::{pubkey::PUBKEY_BYTES, *};
 
-use switchboard_on_demand::{
-    prelude::rust_decimal::Decimal, OracleQuote, QuoteVerifier, SwitchboardQuote,
-};
+use switchboard_on_demand::{OracleQuote, QuoteVerifier, SwitchboardQuote};
 
-use crate::{error::StablecoinError, ORACLE_MAX_AGE, SOL_USD_FEED_ID};
+use crate::{error::StablecoinError, Wad, ORACLE_MAX_AGE, SOL_USD_FEED_ID};
 
 pub fn get_oracle_quote<'b, 'info: 'b>(
     queue: AccountInfo<'info>,
@@ -34,11 +32,13 @@ pub fn get_oracle_quote<'b, 'info: 'b>(
     Ok(quote)
 }
 
-pub fn get_price_from_quote(quote: OracleQuote) -> Result<Decimal> {
-    Ok(quote
+pub fn get_price_from_quote(quote: OracleQuote) -> Result<Wad> {
+    let price = quote
         .feeds()
         .iter()
         .find(|feed| feed.feed_id() == &SOL_USD_FEED_ID)
         .ok_or(StablecoinError::MissingRequiredPriceFeed)?
-        .value())
+        .value();
+
+    Wad::from_decimal(price)
 }
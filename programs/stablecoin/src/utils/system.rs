@@ -1,4 +1,9 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
 
 /// Lifted from private `anchor_lang::common::close`: https://github.com/coral-xyz/anchor/blob/714d5248636493a3d1db1481f16052836ee59e94/lang/src/common.rs#L6
 pub fn close<'info>(info: AccountInfo<'info>, sol_destination: AccountInfo<'info>) -> Result<()> {
@@ -10,3 +15,92 @@ pub fn close<'info>(info: AccountInfo<'info>, sol_destination: AccountInfo<'info
     info.assign(&system_program::ID);
     info.resize(0).map_err(Into::into)
 }
+
+/// Resizes `info` to `new_len`, topping up rent exemption from `payer`.
+pub fn resize<'info>(
+    info: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(info.lamports());
+
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program,
+                Transfer {
+                    from: payer,
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    info.resize(new_len).map_err(Into::into)
+}
+
+/// Creates `info` with `space` bytes owned by `owner`, funded with `lamports` from `payer`. Unlike
+/// `create_account`, succeeds if lamports were sent to the address beforehand.
+pub fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    info: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if info.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: payer,
+                    to: info,
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Transfer {
+                from: payer,
+                to: info.clone(),
+            },
+            signer_seeds,
+        ),
+        lamports,
+    )?;
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: info.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: info,
+            },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::{SafeMath, SafePow};
+
+/// Converts a token amount between decimals, rounding down.
+pub fn scale_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64> {
+    if from_decimals > to_decimals {
+        amount.safe_div(10u64.safe_pow((from_decimals - to_decimals).into())?)
+    } else {
+        amount.safe_mul(10u64.safe_pow((to_decimals - from_decimals).into())?)
+    }
+}
@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
-use switchboard_on_demand::prelude::rust_decimal::Decimal;
 
-use crate::{error::StablecoinError, MAX_BASIS_POINTS};
+use crate::{error::StablecoinError, Wad, MAX_BASIS_POINTS};
 
 pub fn validate_bps(bps: u16) -> Result<()> {
     require_gte!(MAX_BASIS_POINTS, bps, StablecoinError::InvalidBasisPoints);
@@ -9,8 +8,25 @@ pub fn validate_bps(bps: u16) -> Result<()> {
     Ok(())
 }
 
-pub fn validate_price(price: Decimal) -> Result<()> {
-    require_gt!(price, Decimal::ZERO, StablecoinError::InvalidPrice);
+pub fn validate_price(price: Wad) -> Result<()> {
+    require_gt!(price, Wad::ZERO, StablecoinError::InvalidPrice);
+
+    Ok(())
+}
+
+/// Rejects `price` outside the optional bounds set by the signer, both scaled by `WAD`.
+pub fn validate_price_bounds(
+    price: Wad,
+    min_price: Option<u128>,
+    max_price: Option<u128>,
+) -> Result<()> {
+    if let Some(min_price) = min_price {
+        require_gte!(price, Wad(min_price), StablecoinError::PriceBelowMinimum);
+    }
+
+    if let Some(max_price) = max_price {
+        require_gte!(Wad(max_price), price, StablecoinError::PriceAboveMaximum);
+    }
 
     Ok(())
 }
@@ -25,13 +41,34 @@ pub fn validate_ltv(min_ltv_bps: u16, liquidation_threshold_bps: u16) -> Result<
     Ok(())
 }
 
-pub fn validate_above_min_health_factor(
-    health_factor: Decimal,
-    min_ltv_bps: Decimal,
+/// Rejects changes that leave the system collateral ratio below `critical_ratio` and lower than
+/// `before`.
+pub fn validate_system_collateral_ratio(
+    before: Wad,
+    after: Wad,
+    critical_ratio: Wad,
 ) -> Result<()> {
+    require!(
+        after >= critical_ratio || after >= before,
+        StablecoinError::SystemCollateralRatioBelowCritical
+    );
+
+    Ok(())
+}
+
+pub fn validate_not_blocklisted(blocklist_entry: &AccountInfo) -> Result<()> {
+    require!(
+        blocklist_entry.data_is_empty(),
+        StablecoinError::AddressBlocklisted
+    );
+
+    Ok(())
+}
+
+pub fn validate_above_min_health_factor(health_factor: Wad, min_ltv: Wad) -> Result<()> {
     require_gte!(
         health_factor,
-        min_ltv_bps,
+        min_ltv,
         StablecoinError::BelowMinimumHealthFactor
     );
 
@@ -31,6 +31,69 @@ export class StablecoinClient extends ProgramClient<Stablecoin> {
     )[0];
   }
 
+  static getStakePda(position: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("stake"), position.toBuffer()],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getAuctionPda(position: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("auction"), position.toBuffer()],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getStabilityPoolPda() {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("stability_pool")],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getStabilityDepositPda(depositor: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("stability_deposit"), depositor.toBuffer()],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getSavingsPda() {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("savings")],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getSavingsDepositPda(depositor: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("savings_deposit"), depositor.toBuffer()],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getTreasuryPda() {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getPsmPda(stableMint: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("psm"), stableMint.toBuffer()],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
+  static getBlocklistEntryPda(address: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("blocklist"), address.toBuffer()],
+      STABLECOIN_PROGRAM_ID,
+    )[0];
+  }
+
   static getMintPda() {
     return PublicKey.findProgramAddressSync(
       [Buffer.from("mint")],
@@ -0,0 +1,1179 @@
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ACCOUNT_SIZE,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeAccount3Instruction,
+  createInitializeMint2Instruction,
+  createMintToInstruction,
+  createTransferCheckedInstruction,
+  createTransferCheckedWithFeeInstruction,
+  getAssociatedTokenAddressSync,
+  MINT_SIZE,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { BN, Program } from "@coral-xyz/anchor";
+import { FailedTransactionMetadata, LiteSVM } from "litesvm";
+import { LiteSVMProvider } from "anchor-litesvm";
+import { existsSync, readFileSync, writeFileSync } from "fs";
+import { join } from "path";
+import idl from "../../target/idl/stablecoin.json";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { StablecoinClient } from "../StablecoinClient";
+import { readAccountFixture } from "../snapshot";
+import {
+  MINT_DECIMALS,
+  SOL_USD_ORACLE_QUOTE,
+  STABLECOIN_PROGRAM_ID,
+} from "../constants";
+
+/**
+ * Executes every entrypoint of the stablecoin program in LiteSVM and reports
+ * compute units per instruction.
+ *
+ * The program must be built with `bun run build:test` so the stubbed oracle
+ * quote is parsed without signature or staleness verification.
+ *
+ * Fails if any instruction consumes more compute units than in the committed
+ * `compute-units.json`, or is missing from it. Pass `--update` to accept the
+ * new numbers as baseline.
+ */
+
+const PROGRAM_PATH = join(import.meta.dir, "../../target/deploy/stablecoin.so");
+const QUOTE_FIXTURE_PATH = join(
+  import.meta.dir,
+  "fixtures/sol_usd_oracle_quote.json",
+);
+const FIXTURES_DIR = join(import.meta.dir, "../fixtures");
+const REPORT_JSON_PATH = join(import.meta.dir, "compute-units.json");
+const REPORT_MD_PATH = join(import.meta.dir, "compute-units.md");
+// fixed so reports are reproducible between runs
+const UNIX_TIMESTAMP = 1_760_000_000n;
+
+type Measurement = {
+  instruction: string;
+  scenario: string;
+  computeUnits: number;
+};
+
+type Report = {
+  generatedAt: string;
+  measurements: Measurement[];
+  skipped: { instruction: string; reason: string }[];
+};
+
+const measurements: Measurement[] = [];
+const skipped: Report["skipped"] = [];
+
+/**
+ * Loads the stubbed $150 SOL/USD oracle quote written by
+ * `stablecoin-snapshot stub-quote`.
+ */
+function loadOracleQuote() {
+  const { pubkey, account } = readAccountFixture(QUOTE_FIXTURE_PATH);
+
+  if (!pubkey.equals(SOL_USD_ORACLE_QUOTE)) {
+    throw new Error(
+      `Oracle quote fixture is at ${pubkey}, expected ${SOL_USD_ORACLE_QUOTE}`,
+    );
+  }
+
+  return account;
+}
+
+async function createSvm() {
+  const svm = new LiteSVM();
+  svm.addProgramFromFile(STABLECOIN_PROGRAM_ID, PROGRAM_PATH);
+  svm.setAccount(SOL_USD_ORACLE_QUOTE, loadOracleQuote());
+
+  const clock = svm.getClock();
+  clock.unixTimestamp = UNIX_TIMESTAMP;
+  svm.setClock(clock);
+
+  const program = new Program<Stablecoin>(
+    idl as Stablecoin,
+    new LiteSVMProvider(svm),
+  );
+
+  return { svm, program };
+}
+
+function createFundedKeypair(svm: LiteSVM, lamports = 10 * LAMPORTS_PER_SOL) {
+  const keypair = Keypair.generate();
+  svm.airdrop(keypair.publicKey, BigInt(lamports));
+
+  return keypair;
+}
+
+/**
+ * Returns compute units consumed by each top-level stablecoin instruction, in
+ * execution order.
+ */
+function parseComputeUnits(logs: string[]) {
+  const programId = STABLECOIN_PROGRAM_ID.toBase58();
+  const stack: string[] = [];
+  const units: number[] = [];
+
+  for (const log of logs) {
+    const invoke = log.match(/^Program (\w+) invoke \[\d+\]$/);
+    if (invoke) {
+      stack.push(invoke[1]);
+      continue;
+    }
+
+    const consumed = log.match(/^Program (\w+) consumed (\d+) of \d+/);
+    if (consumed && stack.length === 1 && consumed[1] === programId) {
+      units.push(Number(consumed[2]));
+      continue;
+    }
+
+    if (/^Program \w+ (success|failed)/.test(log)) {
+      stack.pop();
+    }
+  }
+
+  return units;
+}
+
+/**
+ * Sends `ixs` in a single transaction and records compute units of each
+ * stablecoin instruction under its label. Unlabeled instructions are executed
+ * but not recorded.
+ */
+function send(
+  svm: LiteSVM,
+  labels: ([string, string] | null)[],
+  ixs: TransactionInstruction[],
+  signers: Signer[],
+) {
+  const tx = new Transaction().add(
+    ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+    ...ixs,
+  );
+  tx.recentBlockhash = svm.latestBlockhash();
+  tx.feePayer = signers[0].publicKey;
+  tx.sign(...signers);
+
+  const result = svm.sendTransaction(tx);
+  svm.expireBlockhash();
+
+  if (result instanceof FailedTransactionMetadata) {
+    throw new Error(
+      `Transaction failed: ${result.err()}\n${result.meta().logs().join("\n")}`,
+    );
+  }
+
+  const units = parseComputeUnits(result.logs());
+
+  labels.forEach((label, i) => {
+    if (!label) return;
+
+    const [instruction, scenario] = label;
+    measurements.push({ instruction, scenario, computeUnits: units[i] });
+  });
+}
+
+function createMint(svm: LiteSVM, payer: Keypair, mintAuthority: PublicKey) {
+  const mint = Keypair.generate();
+
+  send(
+    svm,
+    [],
+    [
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: mint.publicKey,
+        lamports: Number(
+          svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE)),
+        ),
+        space: MINT_SIZE,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      createInitializeMint2Instruction(
+        mint.publicKey,
+        MINT_DECIMALS,
+        mintAuthority,
+        null,
+      ),
+    ],
+    [payer, mint],
+  );
+
+  return mint.publicKey;
+}
+
+async function depositCollateral(
+  svm: LiteSVM,
+  program: Program<Stablecoin>,
+  depositor: Keypair,
+  lamports: number,
+  amountToMint: number,
+  scenario: string | null,
+  tokenProgram = TOKEN_PROGRAM_ID,
+) {
+  const ix = await program.methods
+    .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+    .accounts({
+      depositor: depositor.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      tokenProgram,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .instruction();
+
+  send(
+    svm,
+    [scenario ? ["deposit_collateral", scenario] : null],
+    [ix],
+    [depositor],
+  );
+}
+
+/**
+ * Runs the main lifecycle against an SPL token mint: positions, views,
+ * compliance, stability pool, flash mint, PSM, redemption and liquidations.
+ */
+async function benchLifecycle() {
+  const { svm, program } = await createSvm();
+
+  const authority = createFundedKeypair(svm);
+  const whale = createFundedKeypair(svm, 200 * LAMPORTS_PER_SOL);
+  const [depositorA, depositorB, depositorC, depositorD] = Array.from(
+    { length: 4 },
+    () => createFundedKeypair(svm),
+  );
+
+  const mintPda = StablecoinClient.getMintPda();
+  const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+  const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+  const initializeConfigIx = await program.methods
+    .initializeConfig({
+      liquidationBonusBps: 250, // 2.5%
+      liquidationThresholdBps: 12500, // 125%
+      minLoanToValueBps: 15000, // 150%
+    })
+    .accounts({
+      authority: authority.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["initialize_config", "spl token mint"]],
+    [initializeConfigIx],
+    [authority],
+  );
+
+  const updateConfigIx = await program.methods
+    .updateConfig({
+      liquidationBonusBps: null,
+      liquidationThresholdBps: null,
+      minLoanToValueBps: null,
+      auctionDuration: 600, // 10 minutes
+      auctionPremiumBps: 1000, // 10%
+      auctionDiscountBps: 2000, // 20%
+      maxFlashMint: new BN(1_000_000 * Math.pow(10, MINT_DECIMALS)),
+      flashMintFeeBps: 5, // 0.05%
+      savingsRateBps: 500, // 5%
+      treasuryFeeBps: 2000, // 20%
+      treasurySolWithdrawalLimit: new BN(10 * LAMPORTS_PER_SOL),
+      treasuryTokenWithdrawalLimit: new BN(
+        1_000 * Math.pow(10, MINT_DECIMALS),
+      ),
+    })
+    .accounts({ authority: authority.publicKey })
+    .instruction();
+  send(
+    svm,
+    [
+      [
+        "update_config",
+        "enable auctions, flash mints, savings and treasury fees",
+      ],
+    ],
+    [updateConfigIx],
+    [authority],
+  );
+
+  // compliance
+  const blockedAddress = Keypair.generate().publicKey;
+  const addToBlocklistIx = await program.methods
+    .addToBlocklist(blockedAddress)
+    .accounts({ complianceAuthority: authority.publicKey })
+    .instruction();
+  send(
+    svm,
+    [["add_to_blocklist", "new entry"]],
+    [addToBlocklistIx],
+    [authority],
+  );
+
+  const removeFromBlocklistIx = await program.methods
+    .removeFromBlocklist()
+    .accounts({
+      complianceAuthority: authority.publicKey,
+      blocklistEntry: StablecoinClient.getBlocklistEntryPda(blockedAddress),
+    })
+    .instruction();
+  send(
+    svm,
+    [["remove_from_blocklist", "close entry"]],
+    [removeFromBlocklistIx],
+    [authority],
+  );
+
+  // positions
+  await depositCollateral(
+    svm,
+    program,
+    whale,
+    100 * LAMPORTS_PER_SOL,
+    2_000 * Math.pow(10, MINT_DECIMALS),
+    "new position",
+  );
+  await depositCollateral(
+    svm,
+    program,
+    whale,
+    10 * LAMPORTS_PER_SOL,
+    100 * Math.pow(10, MINT_DECIMALS),
+    "existing position",
+  );
+
+  for (const depositor of [depositorA, depositorB, depositorC, depositorD]) {
+    await depositCollateral(
+      svm,
+      program,
+      depositor,
+      lamports,
+      amountToMint,
+      null,
+    );
+  }
+
+  const whalePosition = StablecoinClient.getPositionPda(whale.publicKey);
+  const viewAccounts = {
+    position: whalePosition,
+    oracleQuote: SOL_USD_ORACLE_QUOTE,
+    clock: SYSVAR_CLOCK_PUBKEY,
+  };
+  send(
+    svm,
+    [
+      ["get_position_health", "open position"],
+      ["get_max_mintable", "open position"],
+      ["get_max_withdrawable", "open position"],
+    ],
+    [
+      await program.methods
+        .getPositionHealth()
+        .accounts(viewAccounts)
+        .instruction(),
+      await program.methods
+        .getMaxMintable()
+        .accounts(viewAccounts)
+        .instruction(),
+      await program.methods
+        .getMaxWithdrawable()
+        .accounts(viewAccounts)
+        .instruction(),
+    ],
+    [whale],
+  );
+
+  const withdrawIx = await program.methods
+    .withdrawCollateral(
+      new BN(LAMPORTS_PER_SOL),
+      new BN(50 * Math.pow(10, MINT_DECIMALS)),
+      null,
+      null,
+    )
+    .accountsPartial({
+      depositor: whale.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .instruction();
+  send(
+    svm,
+    [["withdraw_collateral", "partial withdraw and burn"]],
+    [withdrawIx],
+    [whale],
+  );
+
+  const depositorDAta = getAssociatedTokenAddressSync(
+    mintPda,
+    depositorD.publicKey,
+  );
+  const freezeAccounts = {
+    complianceAuthority: authority.publicKey,
+    tokenAccount: depositorDAta,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  };
+  send(
+    svm,
+    [["freeze_account", "holder token account"]],
+    [
+      await program.methods
+        .freezeAccount()
+        .accounts(freezeAccounts)
+        .instruction(),
+    ],
+    [authority],
+  );
+  send(
+    svm,
+    [["thaw_account", "holder token account"]],
+    [
+      await program.methods
+        .thawAccount()
+        .accounts(freezeAccounts)
+        .instruction(),
+    ],
+    [authority],
+  );
+
+  // treasury
+  const initializeTreasuryIx = await program.methods
+    .initializeTreasury()
+    .accounts({
+      authority: authority.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["initialize_treasury", "new treasury"]],
+    [initializeTreasuryIx],
+    [authority],
+  );
+
+  // stability pool
+  const initializeStabilityPoolIx = await program.methods
+    .initializeStabilityPool()
+    .accounts({
+      authority: authority.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["initialize_stability_pool", "new pool"]],
+    [initializeStabilityPoolIx],
+    [authority],
+  );
+
+  const depositStabilityPoolIx = await program.methods
+    .depositStabilityPool(new BN(600 * Math.pow(10, MINT_DECIMALS)))
+    .accounts({
+      depositor: whale.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["deposit_stability_pool", "new deposit"]],
+    [depositStabilityPoolIx],
+    [whale],
+  );
+
+  const withdrawStabilityPoolIx = await program.methods
+    .withdrawStabilityPool(new BN(100 * Math.pow(10, MINT_DECIMALS)))
+    .accounts({
+      depositor: whale.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["withdraw_stability_pool", "partial withdraw"]],
+    [withdrawStabilityPoolIx],
+    [whale],
+  );
+
+  // flash mint
+  const whaleAta = getAssociatedTokenAddressSync(mintPda, whale.publicKey);
+  const flashAmount = new BN(10_000 * Math.pow(10, MINT_DECIMALS));
+  send(
+    svm,
+    [
+      ["flash_mint", "no receiver program"],
+      ["flash_repay", "repay with fee"],
+    ],
+    [
+      await program.methods
+        .flashMint(flashAmount, Buffer.from([]))
+        .accountsPartial({
+          borrower: whale.publicKey,
+          receiverTokenAccount: whaleAta,
+          receiverBlocklistEntry: StablecoinClient.getBlocklistEntryPda(
+            whale.publicKey,
+          ),
+          receiverProgram: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction(),
+      await program.methods
+        .flashRepay(flashAmount)
+        .accounts({
+          repayer: whale.publicKey,
+          repayerTokenAccount: whaleAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction(),
+    ],
+    [whale],
+  );
+
+  // savings, funded by the flash mint fee
+  const initializeSavingsIx = await program.methods
+    .initializeSavings()
+    .accounts({
+      authority: authority.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["initialize_savings", "new savings"]],
+    [initializeSavingsIx],
+    [authority],
+  );
+
+  const savingsAccounts = {
+    depositor: whale.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  };
+  send(
+    svm,
+    [["deposit_savings", "new deposit"]],
+    [
+      await program.methods
+        .depositSavings(new BN(500 * Math.pow(10, MINT_DECIMALS)))
+        .accounts(savingsAccounts)
+        .instruction(),
+    ],
+    [whale],
+  );
+
+  // accrue a day of interest, then restore the clock for oracle reads
+  const clock = svm.getClock();
+  clock.unixTimestamp = UNIX_TIMESTAMP + 86400n;
+  svm.setClock(clock);
+  send(
+    svm,
+    [["drip", "one day of interest"]],
+    [
+      await program.methods
+        .drip()
+        .accounts({ tokenProgram: TOKEN_PROGRAM_ID })
+        .instruction(),
+    ],
+    [whale],
+  );
+  clock.unixTimestamp = UNIX_TIMESTAMP;
+  svm.setClock(clock);
+
+  send(
+    svm,
+    [["withdraw_savings", "partial withdraw"]],
+    [
+      await program.methods
+        .withdrawSavings(new BN(100 * Math.pow(10, MINT_DECIMALS)))
+        .accounts(savingsAccounts)
+        .instruction(),
+    ],
+    [whale],
+  );
+
+  // peg stability module
+  const stableMint = createMint(svm, authority, authority.publicKey);
+  const whaleStableAta = getAssociatedTokenAddressSync(
+    stableMint,
+    whale.publicKey,
+  );
+  send(
+    svm,
+    [],
+    [
+      createAssociatedTokenAccountIdempotentInstruction(
+        authority.publicKey,
+        whaleStableAta,
+        whale.publicKey,
+        stableMint,
+      ),
+      createMintToInstruction(
+        stableMint,
+        whaleStableAta,
+        authority.publicKey,
+        1_000 * Math.pow(10, MINT_DECIMALS),
+      ),
+    ],
+    [authority],
+  );
+
+  const initializePsmIx = await program.methods
+    .initializePsm({
+      mintFeeBps: 10, // 0.1%
+      redeemFeeBps: 10, // 0.1%
+      debtCeiling: new BN(1_000_000 * Math.pow(10, MINT_DECIMALS)),
+    })
+    .accounts({
+      authority: authority.publicKey,
+      stableMint,
+      stableTokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(svm, [["initialize_psm", "new psm"]], [initializePsmIx], [authority]);
+
+  const updatePsmIx = await program.methods
+    .updatePsm({
+      mintFeeBps: 5, // 0.05%
+      redeemFeeBps: 5, // 0.05%
+      debtCeiling: new BN(2_000_000 * Math.pow(10, MINT_DECIMALS)),
+    })
+    .accounts({
+      authority: authority.publicKey,
+      psm: StablecoinClient.getPsmPda(stableMint),
+    })
+    .instruction();
+  send(
+    svm,
+    [["update_psm", "fees and debt ceiling"]],
+    [updatePsmIx],
+    [authority],
+  );
+
+  const psmAccounts = {
+    user: whale.publicKey,
+    stableMint,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    stableTokenProgram: TOKEN_PROGRAM_ID,
+  };
+  send(
+    svm,
+    [["psm_mint", "stable token into stablecoin"]],
+    [
+      await program.methods
+        .psmMint(new BN(500 * Math.pow(10, MINT_DECIMALS)))
+        .accounts(psmAccounts)
+        .instruction(),
+    ],
+    [whale],
+  );
+  send(
+    svm,
+    [["psm_redeem", "stablecoin into stable token"]],
+    [
+      await program.methods
+        .psmRedeem(new BN(200 * Math.pow(10, MINT_DECIMALS)))
+        .accounts(psmAccounts)
+        .instruction(),
+    ],
+    [whale],
+  );
+
+  // deleverage, with the token program as a stub swap adapter delivering
+  // stablecoin the whale parked in a side account
+  const sideAccount = Keypair.generate();
+  const swapOut = 16 * Math.pow(10, MINT_DECIMALS); // $16 for 0.1 SOL at $150
+  send(
+    svm,
+    [],
+    [
+      SystemProgram.createAccount({
+        fromPubkey: whale.publicKey,
+        newAccountPubkey: sideAccount.publicKey,
+        lamports: Number(
+          svm.minimumBalanceForRentExemption(BigInt(ACCOUNT_SIZE)),
+        ),
+        space: ACCOUNT_SIZE,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      createInitializeAccount3Instruction(
+        sideAccount.publicKey,
+        mintPda,
+        whale.publicKey,
+      ),
+      createTransferCheckedInstruction(
+        whaleAta,
+        mintPda,
+        sideAccount.publicKey,
+        whale.publicKey,
+        swapOut,
+        MINT_DECIMALS,
+      ),
+      await program.methods
+        .updateConfig({
+          liquidationBonusBps: null,
+          liquidationThresholdBps: null,
+          minLoanToValueBps: null,
+          swapAdapter: TOKEN_PROGRAM_ID,
+          deleverageSlippageBps: 100, // 1%
+        })
+        .accounts({ authority: authority.publicKey })
+        .instruction(),
+    ],
+    [whale, sideAccount, authority],
+  );
+
+  const swapIx = createTransferCheckedInstruction(
+    sideAccount.publicKey,
+    mintPda,
+    whaleAta,
+    whale.publicKey,
+    swapOut,
+    MINT_DECIMALS,
+  );
+  const deleverageIx = await program.methods
+    .deleverage(new BN(LAMPORTS_PER_SOL / 10), swapIx.data)
+    .accounts({
+      depositor: whale.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      swapAdapter: TOKEN_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .remainingAccounts(swapIx.keys)
+    .instruction();
+  send(
+    svm,
+    [["deleverage", "stub swap adapter"]],
+    [deleverageIx],
+    [whale],
+  );
+
+  // redemption against a single position
+  const positionD = StablecoinClient.getPositionPda(depositorD.publicKey);
+  const redeemIx = await program.methods
+    .redeem(new BN(100 * Math.pow(10, MINT_DECIMALS)))
+    .accounts({
+      redeemer: whale.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .remainingAccounts([
+      { pubkey: positionD, isSigner: false, isWritable: true },
+      {
+        pubkey: StablecoinClient.getVaultPda(positionD),
+        isSigner: false,
+        isWritable: true,
+      },
+    ])
+    .instruction();
+  send(svm, [["redeem", "one position"]], [redeemIx], [whale]);
+
+  // raise thresholds so the remaining positions become liquidatable
+  const raiseThresholdsIx = await program.methods
+    .updateConfig({
+      liquidationBonusBps: null,
+      liquidationThresholdBps: 30000, // 300%
+      minLoanToValueBps: 35000, // 350%
+    })
+    .accounts({ authority: authority.publicKey })
+    .instruction();
+  send(svm, [], [raiseThresholdsIx], [authority]);
+
+  const positionB = StablecoinClient.getPositionPda(depositorB.publicKey);
+  const startAuctionIx = await program.methods
+    .startAuction()
+    .accountsPartial({
+      keeper: whale.publicKey,
+      depositor: depositorB.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      position: positionB,
+      vault: StablecoinClient.getVaultPda(positionB),
+    })
+    .instruction();
+  send(
+    svm,
+    [["start_auction", "unhealthy position"]],
+    [startAuctionIx],
+    [whale],
+  );
+
+  const takeAuctionIx = await program.methods
+    .takeAuction(new BN(amountToMint))
+    .accountsPartial({
+      bidder: whale.publicKey,
+      depositor: depositorB.publicKey,
+      keeper: whale.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      position: positionB,
+      auction: StablecoinClient.getAuctionPda(positionB),
+      vault: StablecoinClient.getVaultPda(positionB),
+      stakeAccount: null,
+      seizedStake: null,
+      stakeProgram: null,
+    })
+    .instruction();
+  send(svm, [["take_auction", "entire auction"]], [takeAuctionIx], [whale]);
+
+  const positionA = StablecoinClient.getPositionPda(depositorA.publicKey);
+  const liquidateIx = await program.methods
+    .liquidatePosition(new BN(amountToMint), null, null, null)
+    .accountsPartial({
+      liquidator: whale.publicKey,
+      depositor: depositorA.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      position: positionA,
+      vault: StablecoinClient.getVaultPda(positionA),
+      stakeAccount: null,
+      seizedStake: null,
+      stakeProgram: null,
+    })
+    .instruction();
+  send(
+    svm,
+    [["liquidate_position", "full liquidation"]],
+    [liquidateIx],
+    [whale],
+  );
+
+  const positionC = StablecoinClient.getPositionPda(depositorC.publicKey);
+  const liquidateWithStabilityPoolIx = await program.methods
+    .liquidateWithStabilityPool(new BN(amountToMint))
+    .accountsPartial({
+      liquidator: whale.publicKey,
+      depositor: depositorC.publicKey,
+      oracleQuote: SOL_USD_ORACLE_QUOTE,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      position: positionC,
+      vault: StablecoinClient.getVaultPda(positionC),
+      stakeAccount: null,
+      seizedStake: null,
+      stakeProgram: null,
+    })
+    .instruction();
+  send(
+    svm,
+    [["liquidate_with_stability_pool", "full liquidation"]],
+    [liquidateWithStabilityPoolIx],
+    [whale],
+  );
+
+  // withdraw the treasury share of liquidation bonuses
+  const withdrawTreasuryIx = await program.methods
+    .withdrawTreasury(new BN(1_000_000), new BN(0))
+    .accounts({
+      authority: authority.publicKey,
+      recipient: authority.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["withdraw_treasury", "lamports"]],
+    [withdrawTreasuryIx],
+    [authority],
+  );
+
+  for (const instruction of [
+    "stake_collateral",
+    "unstake_collateral",
+    "withdraw_stake",
+  ]) {
+    skipped.push({
+      instruction,
+      reason: "requires a validator vote account",
+    });
+  }
+}
+
+/**
+ * Runs the Token-2022 paths: mint creation with metadata and transfer fee, and
+ * harvesting withheld fees.
+ */
+async function benchToken2022() {
+  const { svm, program } = await createSvm();
+
+  const authority = createFundedKeypair(svm);
+  const depositor = createFundedKeypair(svm);
+  const recipient = createFundedKeypair(svm);
+
+  const mintPda = StablecoinClient.getMintPda();
+  const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+  const transferFeeBps = 100; // 1%
+
+  const initializeConfigIx = await program.methods
+    .initializeConfig({
+      liquidationBonusBps: 250, // 2.5%
+      liquidationThresholdBps: 12500, // 125%
+      minLoanToValueBps: 15000, // 150%
+      metadata: {
+        name: "Stablecoin",
+        symbol: "STBL",
+        uri: "https://example.com/stablecoin.json",
+      },
+      transferFee: {
+        transferFeeBps,
+        maximumFee: new BN(1_000 * Math.pow(10, MINT_DECIMALS)),
+      },
+    })
+    .accounts({
+      authority: authority.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    })
+    .instruction();
+  send(
+    svm,
+    [["initialize_config", "token-2022 mint with metadata and transfer fee"]],
+    [initializeConfigIx],
+    [authority],
+  );
+
+  await depositCollateral(
+    svm,
+    program,
+    depositor,
+    5 * LAMPORTS_PER_SOL,
+    amountToMint,
+    "new position, token-2022 mint",
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  const depositorAta = getAssociatedTokenAddressSync(
+    mintPda,
+    depositor.publicKey,
+    false,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  const recipientAta = getAssociatedTokenAddressSync(
+    mintPda,
+    recipient.publicKey,
+    false,
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  send(
+    svm,
+    [],
+    [
+      await program.methods
+        .initializeTreasury()
+        .accounts({
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction(),
+      createAssociatedTokenAccountIdempotentInstruction(
+        depositor.publicKey,
+        recipientAta,
+        recipient.publicKey,
+        mintPda,
+        TOKEN_2022_PROGRAM_ID,
+      ),
+      createTransferCheckedWithFeeInstruction(
+        depositorAta,
+        mintPda,
+        recipientAta,
+        depositor.publicKey,
+        BigInt(amountToMint),
+        MINT_DECIMALS,
+        BigInt((amountToMint * transferFeeBps) / 10000),
+        [],
+        TOKEN_2022_PROGRAM_ID,
+      ),
+    ],
+    [authority, depositor],
+  );
+
+  const harvestIx = await program.methods
+    .harvestTransferFees()
+    .accounts({
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    })
+    .remainingAccounts([
+      { pubkey: recipientAta, isSigner: false, isWritable: true },
+    ])
+    .instruction();
+  send(
+    svm,
+    [["harvest_transfer_fees", "one token account"]],
+    [harvestIx],
+    [recipient],
+  );
+}
+
+/**
+ * Runs layout migrations of accounts recorded before versioning.
+ */
+async function benchMigrations() {
+  const { svm, program } = await createSvm();
+
+  const payer = createFundedKeypair(svm);
+
+  const config = readAccountFixture(join(FIXTURES_DIR, "config_v0.json"));
+  const position = readAccountFixture(join(FIXTURES_DIR, "position_v0.json"));
+  svm.setAccount(config.pubkey, config.account);
+  svm.setAccount(position.pubkey, position.account);
+
+  const migrateConfigIx = await program.methods
+    .migrateConfig()
+    .accounts({ payer: payer.publicKey })
+    .instruction();
+  send(
+    svm,
+    [["migrate_config", "unversioned layout"]],
+    [migrateConfigIx],
+    [payer],
+  );
+
+  const migratePositionIx = await program.methods
+    .migratePosition()
+    .accounts({
+      payer: payer.publicKey,
+      // depositor is the first field of the unversioned layout
+      depositor: new PublicKey(position.account.data.subarray(8, 40)),
+    })
+    .instruction();
+  send(
+    svm,
+    [["migrate_position", "unversioned layout"]],
+    [migratePositionIx],
+    [payer],
+  );
+}
+
+function readBaseline(): Report | null {
+  if (!existsSync(REPORT_JSON_PATH)) return null;
+
+  return JSON.parse(readFileSync(REPORT_JSON_PATH, "utf-8"));
+}
+
+function measurementKey({ instruction, scenario }: Measurement) {
+  return `${instruction}:${scenario}`;
+}
+
+function getBaselineUnits(baseline: Report | null) {
+  return new Map(
+    (baseline?.measurements ?? []).map((m) => [
+      measurementKey(m),
+      m.computeUnits,
+    ]),
+  );
+}
+
+/**
+ * Returns measurements consuming more compute units than in `baseline`, or
+ * missing from it.
+ */
+function findRegressions(report: Report, baseline: Report | null) {
+  const baselineUnits = getBaselineUnits(baseline);
+
+  return report.measurements.filter((m) => {
+    const previous = baselineUnits.get(measurementKey(m));
+
+    return previous === undefined || m.computeUnits > previous;
+  });
+}
+
+function formatDelta(current: number, previous?: number) {
+  if (previous === undefined) return "-";
+
+  const delta = current - previous;
+  const percent = previous === 0 ? 0 : (delta / previous) * 100;
+  const sign = delta > 0 ? "+" : "";
+
+  return `${sign}${delta} (${sign}${percent.toFixed(1)}%)`;
+}
+
+function formatReport(report: Report, baseline: Report | null) {
+  const baselineUnits = getBaselineUnits(baseline);
+
+  const rows = report.measurements.map(
+    ({ instruction, scenario, computeUnits }) =>
+      `| \`${instruction}\` | ${scenario} | ${computeUnits} | ${formatDelta(
+        computeUnits,
+        baselineUnits.get(`${instruction}:${scenario}`),
+      )} |`,
+  );
+
+  return [
+    "# Compute Units",
+    "",
+    `Generated by \`bun run bench\` at ${report.generatedAt}.`,
+    "",
+    "| Instruction | Scenario | Compute Units | Delta |",
+    "| --- | --- | ---: | ---: |",
+    ...rows,
+    "",
+    ...(report.skipped.length
+      ? [
+          "Skipped:",
+          "",
+          ...report.skipped.map(
+            ({ instruction, reason }) => `- \`${instruction}\`: ${reason}`,
+          ),
+          "",
+        ]
+      : []),
+  ].join("\n");
+}
+
+function writeReport(report: Report, markdown: string) {
+  writeFileSync(REPORT_JSON_PATH, JSON.stringify(report, null, 2) + "\n");
+  writeFileSync(REPORT_MD_PATH, markdown);
+}
+
+const baseline = readBaseline();
+const update = process.argv.includes("--update");
+
+if (!baseline && !update) {
+  throw new Error(
+    `No baseline at ${REPORT_JSON_PATH}. Run \`bun run bench --update\` to record one.`,
+  );
+}
+
+await benchLifecycle();
+await benchToken2022();
+await benchMigrations();
+
+const missing = idl.instructions
+  .map((ix) => ix.name)
+  .filter(
+    (name) =>
+      !measurements.some((m) => m.instruction === name) &&
+      !skipped.some((s) => s.instruction === name),
+  );
+
+if (missing.length) {
+  throw new Error(`Instructions not benchmarked: ${missing.join(", ")}`);
+}
+
+const report = {
+  generatedAt: new Date().toISOString(),
+  measurements,
+  skipped,
+};
+const markdown = formatReport(report, baseline);
+const regressions = findRegressions(report, baseline);
+
+console.log(markdown);
+
+if (regressions.length && !update) {
+  throw new Error(
+    `Compute units above or missing from baseline: ${regressions
+      .map(measurementKey)
+      .join(", ")}. Run \`bun run bench --update\` to accept them.`,
+  );
+}
+
+writeReport(report, markdown);
@@ -0,0 +1,134 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import { expectError, getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  MINT_DECIMALS,
+  SOL_USD_FEED_ID,
+  SOL_USD_ORACLE_QUOTE,
+} from "../constants";
+import { Queue } from "@switchboard-xyz/on-demand";
+import { CrossbarClient } from "@switchboard-xyz/common";
+
+describe("deleverage", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+  let crossbarClient: CrossbarClient;
+  let queue: Queue;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let positionPda: PublicKey;
+
+  const oracleQuote = SOL_USD_ORACLE_QUOTE;
+
+  beforeEach(async () => {
+    [configAuthority, depositor] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client, crossbarClient, queue } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+  });
+
+  test("reject deleverage when swap adapter is not configured", async () => {
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+
+    const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+    const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const collateralAmount = LAMPORTS_PER_SOL; // 1 SOL
+
+    try {
+      await program.methods
+        .deleverage(new BN(collateralAmount), Buffer.alloc(0))
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accounts({
+          depositor: depositor.publicKey,
+          oracleQuote,
+          swapAdapter: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([depositor])
+        .rpc();
+    } catch (err) {
+      expectError(err, "DeleverageDisabled");
+
+      const positionAcc = await client.fetchProgramAccount(
+        positionPda,
+        "position",
+      );
+
+      expect(positionAcc.amountMinted.eq(new BN(amountToMint))).toBeTrue();
+      return;
+    }
+
+    throw new Error("Deleverage should not succeed without swap adapter");
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, positionPda]);
+  });
+});
@@ -7,7 +7,7 @@ import {
   PublicKey,
   SYSVAR_CLOCK_PUBKEY,
 } from "@solana/web3.js";
-import { getSetup, resetAccounts } from "../setup";
+import { expectError, getSetup, resetAccounts } from "../setup";
 import { BN, Program } from "@coral-xyz/anchor";
 import { Stablecoin } from "../../target/types/stablecoin";
 import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -90,7 +90,7 @@ describe("depositCollateral", () => {
     const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
 
     await program.methods
-      .depositCollateral(new BN(lamports), new BN(amountToMint))
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
       .preInstructions([ed25519Ix])
       .accounts({
         depositor: depositor.publicKey,
@@ -123,6 +123,180 @@ describe("depositCollateral", () => {
     const vaultBal = await connection.getBalance(vaultPda);
 
     expect(vaultBal).toBe(lamports);
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.totalCollateral.eq(new BN(lamports))).toBeTrue();
+    expect(configAcc.totalDebt.eq(new BN(amountToMint))).toBeTrue();
+  });
+
+  test("add origination fee to debt", async () => {
+    const originationFeeBps = 50; // 0.5%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        originationFeeBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+    const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+    const fee = (amountToMint * originationFeeBps) / 10000;
+
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([ed25519Ix])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(positionAcc.amountMinted.eq(new BN(amountToMint + fee))).toBeTrue();
+
+    const depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      !PublicKey.isOnCurve(depositor.publicKey),
+    );
+
+    const depositorAtaAcc = await getAccount(connection, depositorAta);
+
+    expect(depositorAtaAcc.amount).toBe(BigInt(amountToMint));
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.totalDebt.eq(new BN(amountToMint + fee))).toBeTrue();
+    expect(configAcc.surplus.eq(new BN(fee))).toBeTrue();
+  });
+
+  test("throws if price is above maximum", async () => {
+    const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+    const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+    const maxPrice = new BN(1); // WAD-scaled, far below the oracle price
+
+    try {
+      await program.methods
+        .depositCollateral(
+          new BN(lamports),
+          new BN(amountToMint),
+          null,
+          maxPrice,
+        )
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accounts({
+          depositor: depositor.publicKey,
+          oracleQuote,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([depositor])
+        .rpc();
+    } catch (err) {
+      expectError(err, "PriceAboveMaximum");
+      return;
+    }
+
+    throw new Error("Deposit above maximum price should fail");
+  });
+
+  test("throws if minting below critical ratio in recovery mode", async () => {
+    const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+    const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    // critical ratio above system collateral ratio enters recovery mode
+    const criticalCollateralRatioBps = 65000; // 650%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        criticalCollateralRatioBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // collateral top-ups are still allowed
+    await program.methods
+      .depositCollateral(new BN(LAMPORTS_PER_SOL), new BN(0), null, null)
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    try {
+      await program.methods
+        .depositCollateral(
+          new BN(LAMPORTS_PER_SOL),
+          new BN(Math.pow(10, MINT_DECIMALS)),
+          null,
+          null,
+        )
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accounts({
+          depositor: depositor.publicKey,
+          oracleQuote,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([depositor])
+        .rpc();
+    } catch (err) {
+      expectError(err, "BelowMinimumHealthFactor");
+      return;
+    }
+
+    throw new Error("Mint below critical ratio should fail in recovery mode");
   });
 
   afterEach(async () => {
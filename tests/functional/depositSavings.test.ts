@@ -0,0 +1,155 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expectError, getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool } from "../surfpool";
+
+describe("depositSavings", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let savingsPda: PublicKey;
+  let savingsDepositPda: PublicKey;
+  let savingsAta: PublicKey;
+  let depositorAta: PublicKey;
+
+  const amount = 100 * Math.pow(10, MINT_DECIMALS); // $100
+
+  beforeEach(async () => {
+    [configAuthority, depositor] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    savingsPda = StablecoinClient.getSavingsPda();
+    savingsDepositPda = StablecoinClient.getSavingsDepositPda(
+      depositor.publicKey,
+    );
+    savingsAta = getAssociatedTokenAddressSync(mintPda, savingsPda, true);
+    depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      !PublicKey.isOnCurve(depositor.publicKey),
+    );
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeSavings()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop depositor mint tokens to deposit
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: depositor.publicKey.toBase58(),
+      update: {
+        amount,
+      },
+    });
+  });
+
+  test("deposit into savings", async () => {
+    await program.methods
+      .depositSavings(new BN(amount))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const savingsAcc = await client.fetchProgramAccount(savingsPda, "savings");
+
+    // shares are issued 1:1 before any interest is dripped
+    expect(savingsAcc.totalShares.eq(new BN(amount))).toBeTrue();
+
+    const savingsDepositAcc = await client.fetchProgramAccount(
+      savingsDepositPda,
+      "savingsDeposit",
+    );
+
+    expect(savingsDepositAcc.depositor.equals(depositor.publicKey)).toBeTrue();
+    expect(savingsDepositAcc.shares.eq(new BN(amount))).toBeTrue();
+
+    const savingsAtaAcc = await getAccount(connection, savingsAta);
+
+    expect(savingsAtaAcc.amount).toBe(BigInt(amount));
+
+    const depositorAtaAcc = await getAccount(connection, depositorAta);
+
+    expect(depositorAtaAcc.amount).toBe(0n);
+  });
+
+  test("throws if amount is 0", async () => {
+    try {
+      await program.methods
+        .depositSavings(new BN(0))
+        .accounts({
+          depositor: depositor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([depositor])
+        .rpc();
+    } catch (err) {
+      expectError(err, "InvalidSavingsAmount");
+      return;
+    }
+
+    throw new Error("Deposit of 0 should not succeed");
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, savingsPda, savingsAta]);
+  });
+});
@@ -0,0 +1,236 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool, TimeTravelConfig } from "../surfpool";
+
+describe("drip", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let savingsPda: PublicKey;
+  let savingsAta: PublicKey;
+  let depositorAta: PublicKey;
+
+  const amount = 100 * Math.pow(10, MINT_DECIMALS); // $100
+  const savingsRateBps = 1000; // 10%
+  const flashMintFeeBps = 5; // 0.05%
+  const flashAmount = 10_000 * Math.pow(10, MINT_DECIMALS); // $10,000
+  const fee = (flashAmount * flashMintFeeBps) / 10000;
+  const WAD = 1_000_000_000_000_000_000n;
+
+  beforeEach(async () => {
+    [configAuthority, depositor] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    savingsPda = StablecoinClient.getSavingsPda();
+    savingsAta = getAssociatedTokenAddressSync(mintPda, savingsPda, true);
+    depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      !PublicKey.isOnCurve(depositor.publicKey),
+    );
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // enable flash mints and savings rate
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        maxFlashMint: new BN(flashAmount),
+        flashMintFeeBps,
+        savingsRateBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeSavings()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop depositor mint tokens to deposit and pay flash mint fee
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: depositor.publicKey.toBase58(),
+      update: {
+        amount: amount + fee,
+      },
+    });
+
+    // include airdropped tokens in mint supply so they can be burned
+    await Surfpool.initMint({
+      mint: mintPda,
+      mintAuthority: mintPda,
+      freezeAuthority: mintPda,
+      supply: BigInt(amount + fee),
+      decimals: MINT_DECIMALS,
+    });
+
+    await program.methods
+      .depositSavings(new BN(amount))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    // flash mint fee funds the surplus paid out as savings interest
+    const flashRepayIx = await program.methods
+      .flashRepay(new BN(flashAmount))
+      .accounts({
+        repayer: depositor.publicKey,
+        repayerTokenAccount: depositorAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+
+    await program.methods
+      .flashMint(new BN(flashAmount), Buffer.from([]))
+      .accountsPartial({
+        borrower: depositor.publicKey,
+        receiverTokenAccount: depositorAta,
+        receiverBlocklistEntry: StablecoinClient.getBlocklistEntryPda(
+          depositor.publicKey,
+        ),
+        receiverProgram: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .postInstructions([flashRepayIx])
+      .signers([depositor])
+      .rpc();
+  });
+
+  test("drip interest from surplus", async () => {
+    const { epoch } = await connection.getEpochInfo();
+    await Surfpool.timeTravel({
+      config: TimeTravelConfig.Epoch,
+      value: epoch + 2,
+    });
+
+    await program.methods
+      .drip()
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const savingsAtaAcc = await getAccount(connection, savingsAta);
+    const interest = savingsAtaAcc.amount - BigInt(amount);
+
+    expect(interest > 0n).toBeTrue();
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.surplus.toString()).toBe(
+      (BigInt(fee) - interest).toString(),
+    );
+
+    const savingsAcc = await client.fetchProgramAccount(savingsPda, "savings");
+
+    // deposit value grows with chi and never exceeds minted interest
+    const depositValue =
+      (BigInt(amount) * BigInt(savingsAcc.chi.toString())) / WAD;
+
+    expect(depositValue > BigInt(amount)).toBeTrue();
+    expect(depositValue <= savingsAtaAcc.amount).toBeTrue();
+  });
+
+  test("interest is capped at surplus", async () => {
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        savingsRateBps: 10000, // 100%
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const { epoch } = await connection.getEpochInfo();
+    await Surfpool.timeTravel({
+      config: TimeTravelConfig.Epoch,
+      value: epoch + 20,
+    });
+
+    await program.methods
+      .drip()
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.surplus.eqn(0)).toBeTrue();
+
+    const savingsAtaAcc = await getAccount(connection, savingsAta);
+
+    expect(savingsAtaAcc.amount).toBe(BigInt(amount + fee));
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, savingsPda, savingsAta]);
+  });
+});
@@ -0,0 +1,206 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { expectError, getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { Surfpool } from "../surfpool";
+
+describe("flashMint", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let borrower: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let borrowerAta: PublicKey;
+
+  const maxFlashMint = 1_000_000 * Math.pow(10, MINT_DECIMALS); // $1,000,000
+  const flashMintFeeBps = 5; // 0.05%
+  const amount = 10_000 * Math.pow(10, MINT_DECIMALS); // $10,000
+  const fee = (amount * flashMintFeeBps) / 10000;
+
+  beforeEach(async () => {
+    [configAuthority, borrower] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: borrower.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // enable flash mints
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        maxFlashMint: new BN(maxFlashMint),
+        flashMintFeeBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop borrower mint tokens to pay fee
+    borrowerAta = await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: borrower.publicKey.toBase58(),
+      update: {
+        amount: fee,
+      },
+    });
+
+    // include airdropped tokens in mint supply so they can be burned
+    await Surfpool.initMint({
+      mint: mintPda,
+      mintAuthority: mintPda,
+      freezeAuthority: mintPda,
+      supply: BigInt(fee),
+      decimals: MINT_DECIMALS,
+    });
+  });
+
+  test("flash mint and repay in same transaction", async () => {
+    const flashRepayIx = await program.methods
+      .flashRepay(new BN(amount))
+      .accounts({
+        repayer: borrower.publicKey,
+        repayerTokenAccount: borrowerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+
+    await program.methods
+      .flashMint(new BN(amount), Buffer.from([]))
+      .accountsPartial({
+        borrower: borrower.publicKey,
+        receiverTokenAccount: borrowerAta,
+        receiverBlocklistEntry: StablecoinClient.getBlocklistEntryPda(
+          borrower.publicKey,
+        ),
+        receiverProgram: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .postInstructions([flashRepayIx])
+      .signers([borrower])
+      .rpc();
+
+    const borrowerAtaAcc = await getAccount(connection, borrowerAta);
+
+    expect(borrowerAtaAcc.amount).toBe(0n);
+  });
+
+  test("throws if flash mint is not repaid", async () => {
+    try {
+      await program.methods
+        .flashMint(new BN(amount), Buffer.from([]))
+        .accountsPartial({
+          borrower: borrower.publicKey,
+          receiverTokenAccount: borrowerAta,
+          receiverBlocklistEntry: StablecoinClient.getBlocklistEntryPda(
+            borrower.publicKey,
+          ),
+          receiverProgram: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([borrower])
+        .rpc();
+    } catch (err) {
+      expectError(err, "MissingFlashRepay");
+      return;
+    }
+
+    throw new Error("Flash mint should not succeed without repayment");
+  });
+
+  test("throws if receiver is blocklisted", async () => {
+    const receiver = Keypair.generate();
+    const receiverAta = await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: receiver.publicKey.toBase58(),
+      update: {
+        amount: 0,
+      },
+    });
+
+    await program.methods
+      .addToBlocklist(receiver.publicKey)
+      .accounts({
+        complianceAuthority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const flashRepayIx = await program.methods
+      .flashRepay(new BN(amount))
+      .accounts({
+        repayer: borrower.publicKey,
+        repayerTokenAccount: borrowerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+
+    try {
+      await program.methods
+        .flashMint(new BN(amount), Buffer.from([]))
+        .accountsPartial({
+          borrower: borrower.publicKey,
+          receiverTokenAccount: receiverAta,
+          receiverBlocklistEntry: StablecoinClient.getBlocklistEntryPda(
+            receiver.publicKey,
+          ),
+          receiverProgram: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .postInstructions([flashRepayIx])
+        .signers([borrower])
+        .rpc();
+    } catch (err) {
+      expectError(err, "AddressBlocklisted");
+      return;
+    }
+
+    throw new Error("Flash mint to blocklisted receiver should fail");
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda]);
+  });
+});
@@ -0,0 +1,130 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { Surfpool } from "../surfpool";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+
+describe("flashRepay", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let repayer: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let repayerAta: PublicKey;
+
+  const maxFlashMint = 1_000_000 * Math.pow(10, MINT_DECIMALS); // $1,000,000
+  const flashMintFeeBps = 5; // 0.05%
+  const amount = 10_000 * Math.pow(10, MINT_DECIMALS); // $10,000
+  const fee = (amount * flashMintFeeBps) / 10000;
+
+  beforeEach(async () => {
+    [configAuthority, repayer] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: repayer.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // enable flash mints
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        maxFlashMint: new BN(maxFlashMint),
+        flashMintFeeBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    repayerAta = getAssociatedTokenAddressSync(
+      mintPda,
+      repayer.publicKey,
+      !PublicKey.isOnCurve(repayer.publicKey),
+    );
+  });
+
+  test("burn flash minted amount with fee", async () => {
+    // airdrop repayer principal and fee to repay
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: repayer.publicKey.toBase58(),
+      update: {
+        amount: amount + fee,
+      },
+    });
+
+    // include airdropped tokens in mint supply so they can be burned
+    await Surfpool.initMint({
+      mint: mintPda,
+      mintAuthority: mintPda,
+      freezeAuthority: mintPda,
+      supply: BigInt(amount + fee),
+      decimals: MINT_DECIMALS,
+    });
+
+    await program.methods
+      .flashRepay(new BN(amount))
+      .accounts({
+        repayer: repayer.publicKey,
+        repayerTokenAccount: repayerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([repayer])
+      .rpc();
+
+    const repayerAtaAcc = await getAccount(connection, repayerAta);
+
+    expect(repayerAtaAcc.amount).toBe(0n);
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.surplus.eq(new BN(fee))).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda]);
+  });
+});
@@ -0,0 +1,85 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Surfpool } from "../surfpool";
+
+describe("freezeAccount", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let holder: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let holderAta: PublicKey;
+
+  beforeEach(async () => {
+    [configAuthority, holder] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    holderAta = await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: holder.publicKey.toBase58(),
+      update: {
+        amount: 0,
+      },
+    });
+  });
+
+  test("freeze token account", async () => {
+    await program.methods
+      .freezeAccount()
+      .accounts({
+        complianceAuthority: configAuthority.publicKey,
+        tokenAccount: holderAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const holderAtaAcc = await getAccount(connection, holderAta);
+
+    expect(holderAtaAcc.isFrozen).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, holderAta]);
+  });
+});
@@ -0,0 +1,196 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import {
+  createAssociatedTokenAccount,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+  transferChecked,
+} from "@solana/spl-token";
+import {
+  MINT_DECIMALS,
+  SOL_USD_FEED_ID,
+  SOL_USD_ORACLE_QUOTE,
+} from "../constants";
+import { Queue } from "@switchboard-xyz/on-demand";
+import { CrossbarClient } from "@switchboard-xyz/common";
+
+describe("harvestTransferFees", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+  let crossbarClient: CrossbarClient;
+  let queue: Queue;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+  let recipient: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let treasuryPda: PublicKey;
+  let treasuryAta: PublicKey;
+
+  const oracleQuote = SOL_USD_ORACLE_QUOTE;
+  const transferFeeBps = 100; // 1%
+
+  beforeEach(async () => {
+    [configAuthority, depositor, recipient] = Array.from({ length: 3 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client, crossbarClient, queue } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+        lamports: LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: recipient.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    treasuryPda = StablecoinClient.getTreasuryPda();
+    treasuryAta = getAssociatedTokenAddressSync(
+      mintPda,
+      treasuryPda,
+      true,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+        metadata: null,
+        transferFee: {
+          transferFeeBps,
+          maximumFee: new BN(1_000 * Math.pow(10, MINT_DECIMALS)),
+        },
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeTreasury()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+  });
+
+  test("harvest withheld transfer fees to treasury", async () => {
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+    const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([ed25519Ix])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const recipientAta = await createAssociatedTokenAccount(
+      connection,
+      depositor,
+      mintPda,
+      recipient.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    // transfer fee is withheld in recipient token account
+    await transferChecked(
+      connection,
+      depositor,
+      depositorAta,
+      mintPda,
+      recipientAta,
+      depositor,
+      amountToMint,
+      MINT_DECIMALS,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    const fee = (amountToMint * transferFeeBps) / 10000;
+
+    await program.methods
+      .harvestTransferFees()
+      .accounts({
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        { pubkey: recipientAta, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    const treasuryAtaAcc = await getAccount(
+      connection,
+      treasuryAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    expect(treasuryAtaAcc.amount).toBe(BigInt(fee));
+
+    const recipientAtaAcc = await getAccount(
+      connection,
+      recipientAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    expect(recipientAtaAcc.amount).toBe(BigInt(amountToMint - fee));
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, treasuryPda, treasuryAta]);
+  });
+});
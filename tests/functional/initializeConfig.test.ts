@@ -1,10 +1,21 @@
 import { afterEach, beforeEach, describe, expect, test } from "bun:test";
 import { StablecoinClient } from "../StablecoinClient";
-import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
 import { getSetup, resetAccounts } from "../setup";
-import { Program } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import { Stablecoin } from "../../target/types/stablecoin";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  getMint,
+  getTokenMetadata,
+  getTransferFeeConfig,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 
 describe("initializeConfig", () => {
   let client: StablecoinClient;
@@ -58,6 +69,97 @@ describe("initializeConfig", () => {
     expect(configAcc.minLoanToValueBps).toBe(minLoanToValueBps);
   });
 
+  test("initialize config with Token-2022 metadata and transfer fee", async () => {
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+    const metadata = {
+      name: "Stablecoin",
+      symbol: "USD",
+      uri: "https://example.com/stablecoin.json",
+    };
+    const transferFeeBps = 10; // 0.1%
+    const maximumFee = new BN(1_000_000);
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+        metadata,
+        transferFee: {
+          transferFeeBps,
+          maximumFee,
+        },
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const mintAcc = await getMint(
+      connection,
+      mintPda,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    expect(mintAcc.mintAuthority.equals(mintPda)).toBeTrue();
+
+    const transferFeeConfig = getTransferFeeConfig(mintAcc);
+
+    expect(transferFeeConfig.newerTransferFee.transferFeeBasisPoints).toBe(
+      transferFeeBps,
+    );
+    expect(transferFeeConfig.newerTransferFee.maximumFee).toBe(
+      BigInt(maximumFee.toString()),
+    );
+
+    const tokenMetadata = await getTokenMetadata(
+      connection,
+      mintPda,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    expect(tokenMetadata.name).toBe(metadata.name);
+    expect(tokenMetadata.symbol).toBe(metadata.symbol);
+    expect(tokenMetadata.uri).toBe(metadata.uri);
+  });
+
+  test("initialize config with pre-funded mint address", async () => {
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    // lamports sent to the mint address would make a plain create_account fail
+    await getSetup([
+      {
+        publicKey: mintPda,
+        lamports: LAMPORTS_PER_SOL,
+      },
+    ]);
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const mintAcc = await getMint(connection, mintPda);
+
+    expect(mintAcc.mintAuthority.equals(mintPda)).toBeTrue();
+  });
+
   afterEach(async () => {
     await resetAccounts([configPda, mintPda]);
   });
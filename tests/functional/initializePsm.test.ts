@@ -0,0 +1,98 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool } from "../surfpool";
+
+describe("initializePsm", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let stableMint: PublicKey;
+  let psmPda: PublicKey;
+  let reserveAta: PublicKey;
+
+  const mintFeeBps = 10; // 0.1%
+  const redeemFeeBps = 20; // 0.2%
+  const debtCeiling = 1_000_000 * Math.pow(10, MINT_DECIMALS); // $1,000,000
+
+  beforeEach(async () => {
+    configAuthority = Keypair.generate();
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    stableMint = await Surfpool.initMint({});
+    psmPda = StablecoinClient.getPsmPda(stableMint);
+    reserveAta = getAssociatedTokenAddressSync(stableMint, psmPda, true);
+  });
+
+  test("initialize psm", async () => {
+    await program.methods
+      .initializePsm({
+        mintFeeBps,
+        redeemFeeBps,
+        debtCeiling: new BN(debtCeiling),
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        stableMint,
+        stableTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const psmAcc = await client.fetchProgramAccount(psmPda, "psm");
+
+    expect(psmAcc.stableMint.equals(stableMint)).toBeTrue();
+    expect(psmAcc.mintFeeBps).toBe(mintFeeBps);
+    expect(psmAcc.redeemFeeBps).toBe(redeemFeeBps);
+    expect(psmAcc.debtCeiling.eq(new BN(debtCeiling))).toBeTrue();
+    expect(psmAcc.debt.toNumber()).toBe(0);
+
+    const reserveAtaAcc = await getAccount(connection, reserveAta);
+
+    expect(reserveAtaAcc.owner.equals(psmPda)).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda]);
+  });
+});
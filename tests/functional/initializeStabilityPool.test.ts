@@ -0,0 +1,81 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+
+describe("initializeStabilityPool", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let stabilityPoolPda: PublicKey;
+  let poolAta: PublicKey;
+
+  beforeEach(async () => {
+    configAuthority = Keypair.generate();
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    stabilityPoolPda = StablecoinClient.getStabilityPoolPda();
+    poolAta = getAssociatedTokenAddressSync(mintPda, stabilityPoolPda, true);
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+  });
+
+  test("initialize stability pool", async () => {
+    await program.methods
+      .initializeStabilityPool()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const stabilityPoolAcc = await client.fetchProgramAccount(
+      stabilityPoolPda,
+      "stabilityPool",
+    );
+
+    expect(stabilityPoolAcc.totalDeposits.toNumber()).toBe(0);
+    expect(stabilityPoolAcc.p.toString()).toBe("1000000000000000000");
+    expect(stabilityPoolAcc.s.toNumber()).toBe(0);
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, stabilityPoolPda, poolAta]);
+  });
+});
@@ -5,9 +5,11 @@ import {
   Keypair,
   LAMPORTS_PER_SOL,
   PublicKey,
+  STAKE_CONFIG_ID,
+  StakeProgram,
   SYSVAR_CLOCK_PUBKEY,
 } from "@solana/web3.js";
-import { getSetup, resetAccounts } from "../setup";
+import { expectError, getSetup, resetAccounts } from "../setup";
 import { BN, Program } from "@coral-xyz/anchor";
 import { Stablecoin } from "../../target/types/stablecoin";
 import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -39,6 +41,8 @@ describe("liquidatePosition", () => {
   let positionPda: PublicKey;
   let depositorAta: PublicKey;
   let vaultPda: PublicKey;
+  let treasuryPda: PublicKey;
+  let treasuryAta: PublicKey;
 
   const oracleQuote = SOL_USD_ORACLE_QUOTE;
   const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
@@ -68,6 +72,8 @@ describe("liquidatePosition", () => {
 
     configPda = StablecoinClient.getConfigPda();
     mintPda = StablecoinClient.getMintPda();
+    treasuryPda = StablecoinClient.getTreasuryPda();
+    treasuryAta = getAssociatedTokenAddressSync(mintPda, treasuryPda, true);
 
     // initialize config
     const liquidationBonusBps = 250; // 2.5%
@@ -87,6 +93,15 @@ describe("liquidatePosition", () => {
       .signers([configAuthority])
       .rpc();
 
+    await program.methods
+      .initializeTreasury()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
     positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
 
     const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
@@ -95,7 +110,7 @@ describe("liquidatePosition", () => {
 
     // deposit SOL as collateral
     await program.methods
-      .depositCollateral(new BN(lamports), new BN(amountToMint))
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
       .preInstructions([ed25519Ix])
       .accounts({
         depositor: depositor.publicKey,
@@ -155,7 +170,7 @@ describe("liquidatePosition", () => {
     ]);
 
     await program.methods
-      .liquidatePosition(new BN(amountToBurn))
+      .liquidatePosition(new BN(amountToBurn), null, null, null)
       .preInstructions([ed25519Ix])
       .accountsPartial({
         liquidator: liquidator.publicKey,
@@ -165,6 +180,9 @@ describe("liquidatePosition", () => {
         clock: SYSVAR_CLOCK_PUBKEY,
         position: positionPda,
         vault: vaultPda,
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
       })
       .signers([liquidator])
       .rpc();
@@ -199,7 +217,382 @@ describe("liquidatePosition", () => {
     expect(liquidatorAtaAcc.amount).toBe(0n);
   });
 
+  test("return residual collateral to depositor on full liquidation", async () => {
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const amountToBurn = amountToMint;
+
+    // airdrop liquidator mint tokens to burn
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: liquidator.publicKey.toBase58(),
+      update: {
+        amount: amountToBurn,
+      },
+    });
+
+    const preLiquidatorBal = await connection.getBalance(liquidator.publicKey);
+    const preDepositorBal = await connection.getBalance(depositor.publicKey);
+    const preVaultBal = await connection.getBalance(vaultPda);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    await program.methods
+      .liquidatePosition(new BN(amountToBurn), null, null, null)
+      .preInstructions([ed25519Ix])
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
+      })
+      .signers([liquidator])
+      .rpc();
+
+    const postLiquidatorBal = await connection.getBalance(liquidator.publicKey);
+    const postDepositorBal = await connection.getBalance(depositor.publicKey);
+    const liquidatedLamports = postLiquidatorBal - preLiquidatorBal;
+
+    expect(liquidatedLamports).toBeGreaterThan(0);
+    expect(liquidatedLamports).toBeLessThan(preVaultBal);
+    // residual collateral and position rent are returned to depositor
+    expect(postDepositorBal).toBeGreaterThan(
+      preDepositorBal + preVaultBal - liquidatedLamports,
+    );
+
+    const postVaultBal = await connection.getBalance(vaultPda);
+
+    expect(postVaultBal).toBe(0);
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(positionAcc).toBeNull();
+  });
+
+  test("send share of liquidation bonus to treasury", async () => {
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+    const treasuryFeeBps = 5000; // 50%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+        treasuryFeeBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const amountToBurn = amountToMint / 2;
+
+    // airdrop liquidator mint tokens to burn
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: liquidator.publicKey.toBase58(),
+      update: {
+        amount: amountToBurn,
+      },
+    });
+
+    const preLiquidatorBal = await connection.getBalance(liquidator.publicKey);
+    const preTreasuryBal = await connection.getBalance(treasuryPda);
+    const preVaultBal = await connection.getBalance(vaultPda);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    await program.methods
+      .liquidatePosition(new BN(amountToBurn), null, null, null)
+      .preInstructions([ed25519Ix])
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
+      })
+      .signers([liquidator])
+      .rpc();
+
+    const postLiquidatorBal = await connection.getBalance(liquidator.publicKey);
+    const postTreasuryBal = await connection.getBalance(treasuryPda);
+    const postVaultBal = await connection.getBalance(vaultPda);
+    const treasuryFee = postTreasuryBal - preTreasuryBal;
+
+    expect(treasuryFee).toBeGreaterThan(0);
+    // seized collateral is split between liquidator and treasury, less transaction fee
+    expect(postLiquidatorBal - preLiquidatorBal).toBeLessThanOrEqual(
+      preVaultBal - postVaultBal - treasuryFee,
+    );
+  });
+
+  test("liquidate before treasury is initialized", async () => {
+    await resetAccounts([treasuryPda, treasuryAta]);
+
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+    const treasuryFeeBps = 5000; // 50%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+        treasuryFeeBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const amountToBurn = amountToMint / 2;
+
+    // airdrop liquidator mint tokens to burn
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: liquidator.publicKey.toBase58(),
+      update: {
+        amount: amountToBurn,
+      },
+    });
+
+    const prePositionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    await program.methods
+      .liquidatePosition(new BN(amountToBurn), null, null, null)
+      .preInstructions([ed25519Ix])
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
+      })
+      .signers([liquidator])
+      .rpc();
+
+    const postPositionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(
+      prePositionAcc.amountMinted.eq(
+        postPositionAcc.amountMinted.add(new BN(amountToBurn)),
+      ),
+    ).toBeTrue();
+    // no treasury fee is charged until treasury is initialized
+    expect(await connection.getBalance(treasuryPda)).toBe(0);
+  });
+
+  test("seize staked collateral by splitting the stake", async () => {
+    const stakePda = StablecoinClient.getStakePda(positionPda);
+    const seizedStake = Keypair.generate();
+    const amountToStake = 4.5 * LAMPORTS_PER_SOL; // 4.5 SOL, leaving 0.5 SOL liquid
+
+    // any validator on the forked cluster
+    const { current } = await connection.getVoteAccounts();
+
+    await program.methods
+      .stakeCollateral(new BN(amountToStake))
+      .accounts({
+        depositor: depositor.publicKey,
+        voteAccount: new PublicKey(current[0].votePubkey),
+        stakeConfig: STAKE_CONFIG_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // seizes more than the liquid vault holds
+    const amountToBurn = amountToMint / 2;
+
+    // airdrop liquidator mint tokens to burn
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: liquidator.publicKey.toBase58(),
+      update: {
+        amount: amountToBurn,
+      },
+    });
+
+    const preStakeBal = await connection.getBalance(stakePda);
+
+    await program.methods
+      .liquidatePosition(new BN(amountToBurn), null, null, null)
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+        stakeAccount: stakePda,
+        seizedStake: seizedStake.publicKey,
+        stakeProgram: StakeProgram.programId,
+      })
+      .signers([liquidator, seizedStake])
+      .rpc();
+
+    // liquid collateral is paid out before the stake is split
+    expect(await connection.getBalance(vaultPda)).toBe(0);
+
+    const postStakeBal = await connection.getBalance(stakePda);
+    const seizedStakeInfo = await connection.getParsedAccountInfo(
+      seizedStake.publicKey,
+    );
+
+    expect(seizedStakeInfo.value.owner).toStrictEqual(StakeProgram.programId);
+
+    const { authorized } = (seizedStakeInfo.value.data as any).parsed.info.meta;
+
+    expect(authorized.staker).toBe(liquidator.publicKey.toBase58());
+    expect(authorized.withdrawer).toBe(liquidator.publicKey.toBase58());
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(
+      positionAcc.stakedCollateral.eq(
+        new BN(amountToStake - (preStakeBal - postStakeBal)),
+      ),
+    ).toBeTrue();
+    expect(
+      positionAcc.amountMinted.eq(new BN(amountToMint - amountToBurn)),
+    ).toBeTrue();
+  });
+
+  test("throws if collateral out is below minimum", async () => {
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const amountToBurn = amountToMint / 2;
+
+    // airdrop liquidator mint tokens to burn
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: liquidator.publicKey.toBase58(),
+      update: {
+        amount: amountToBurn,
+      },
+    });
+
+    // more than the entire vault
+    const minCollateralOut = new BN(100 * LAMPORTS_PER_SOL);
+
+    try {
+      await program.methods
+        .liquidatePosition(new BN(amountToBurn), null, null, minCollateralOut)
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accountsPartial({
+          liquidator: liquidator.publicKey,
+          depositor: depositor.publicKey,
+          oracleQuote,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          position: positionPda,
+          vault: vaultPda,
+          stakeAccount: null,
+          seizedStake: null,
+          stakeProgram: null,
+        })
+        .signers([liquidator])
+        .rpc();
+    } catch (err) {
+      expectError(err, "CollateralOutBelowMinimum");
+      return;
+    }
+
+    throw new Error("Liquidation below minimum collateral out should fail");
+  });
+
   afterEach(async () => {
-    await resetAccounts([configPda, mintPda]);
+    await resetAccounts([configPda, mintPda, treasuryPda, treasuryAta]);
   });
 });
@@ -0,0 +1,325 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  STAKE_CONFIG_ID,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  MINT_DECIMALS,
+  SOL_USD_FEED_ID,
+  SOL_USD_ORACLE_QUOTE,
+} from "../constants";
+import {
+  getAssociatedTokenAddressSync,
+  Queue,
+} from "@switchboard-xyz/on-demand";
+import { CrossbarClient } from "@switchboard-xyz/common";
+import { Surfpool } from "../surfpool";
+
+describe("liquidateWithStabilityPool", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+  let crossbarClient: CrossbarClient;
+  let queue: Queue;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+  let liquidator: Keypair;
+  let provider: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let positionPda: PublicKey;
+  let depositorAta: PublicKey;
+  let vaultPda: PublicKey;
+  let stabilityPoolPda: PublicKey;
+  let poolAta: PublicKey;
+
+  const oracleQuote = SOL_USD_ORACLE_QUOTE;
+  const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+  const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+  const amountToProvide = 500 * Math.pow(10, MINT_DECIMALS); // $500
+
+  beforeEach(async () => {
+    [configAuthority, depositor, liquidator, provider] = Array.from(
+      { length: 4 },
+      () => Keypair.generate(),
+    );
+
+    ({ client, crossbarClient, queue } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: liquidator.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: provider.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    // deposit SOL as collateral
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([ed25519Ix])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      !PublicKey.isOnCurve(depositor.publicKey),
+    );
+
+    vaultPda = StablecoinClient.getVaultPda(positionPda);
+    stabilityPoolPda = StablecoinClient.getStabilityPoolPda();
+    poolAta = getAssociatedTokenAddressSync(mintPda, stabilityPoolPda, true);
+
+    await program.methods
+      .initializeStabilityPool()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop provider mint tokens to deposit into stability pool
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: provider.publicKey.toBase58(),
+      update: {
+        amount: amountToProvide,
+      },
+    });
+
+    await program.methods
+      .depositStabilityPool(new BN(amountToProvide))
+      .accounts({
+        depositor: provider.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([provider])
+      .rpc();
+  });
+
+  test("liquidate half of undercollaterized position with stability pool", async () => {
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const amountToBurn = amountToMint / 2;
+
+    const prePoolBal = await connection.getBalance(stabilityPoolPda);
+    const preVaultBal = await connection.getBalance(vaultPda);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    await program.methods
+      .liquidateWithStabilityPool(new BN(amountToBurn))
+      .preInstructions([ed25519Ix])
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
+      })
+      .signers([liquidator])
+      .rpc();
+
+    const postPoolBal = await connection.getBalance(stabilityPoolPda);
+    const postVaultBal = await connection.getBalance(vaultPda);
+
+    expect(postPoolBal - prePoolBal).toBe(preVaultBal - postVaultBal);
+
+    const stabilityPoolAcc = await client.fetchProgramAccount(
+      stabilityPoolPda,
+      "stabilityPool",
+    );
+
+    expect(
+      stabilityPoolAcc.totalDeposits.eq(new BN(amountToProvide - amountToBurn)),
+    ).toBeTrue();
+    expect(stabilityPoolAcc.s.gtn(0)).toBeTrue();
+
+    const poolAtaAcc = await getAccount(connection, poolAta);
+
+    expect(poolAtaAcc.amount).toBe(BigInt(amountToProvide - amountToBurn));
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(
+      positionAcc.amountMinted.eq(new BN(amountToMint - amountToBurn)),
+    ).toBeTrue();
+  });
+
+  test("seize staked collateral by splitting the stake", async () => {
+    const stakePda = StablecoinClient.getStakePda(positionPda);
+    const seizedStake = Keypair.generate();
+    const amountToStake = 4.5 * LAMPORTS_PER_SOL; // 4.5 SOL, leaving 0.5 SOL liquid
+
+    // any validator on the forked cluster
+    const { current } = await connection.getVoteAccounts();
+
+    await program.methods
+      .stakeCollateral(new BN(amountToStake))
+      .accounts({
+        depositor: depositor.publicKey,
+        voteAccount: new PublicKey(current[0].votePubkey),
+        stakeConfig: STAKE_CONFIG_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    // update config to meet liquidation requirements
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // seizes more than the liquid vault holds
+    const amountToBurn = amountToMint / 2;
+
+    const prePoolBal = await connection.getBalance(stabilityPoolPda);
+    const preVaultBal = await connection.getBalance(vaultPda);
+    const preStakeBal = await connection.getBalance(stakePda);
+
+    await program.methods
+      .liquidateWithStabilityPool(new BN(amountToBurn))
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+        stakeAccount: stakePda,
+        seizedStake: seizedStake.publicKey,
+        stakeProgram: StakeProgram.programId,
+      })
+      .signers([liquidator, seizedStake])
+      .rpc();
+
+    // liquid collateral is paid out before the stake is split
+    expect(await connection.getBalance(vaultPda)).toBe(0);
+
+    const postPoolBal = await connection.getBalance(stabilityPoolPda);
+    const postStakeBal = await connection.getBalance(stakePda);
+    const stakeSeized = preStakeBal - postStakeBal;
+
+    // pool receives the vault balance and the liquidator's payment for the stake
+    expect(postPoolBal - prePoolBal).toBe(preVaultBal + stakeSeized);
+
+    const seizedStakeInfo = await connection.getParsedAccountInfo(
+      seizedStake.publicKey,
+    );
+    const { authorized } = (seizedStakeInfo.value.data as any).parsed.info.meta;
+
+    expect(authorized.staker).toBe(liquidator.publicKey.toBase58());
+    expect(authorized.withdrawer).toBe(liquidator.publicKey.toBase58());
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(
+      positionAcc.stakedCollateral.eq(new BN(amountToStake - stakeSeized)),
+    ).toBeTrue();
+    expect(
+      positionAcc.amountMinted.eq(new BN(amountToMint - amountToBurn)),
+    ).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, stabilityPoolPda, poolAta]);
+  });
+});
@@ -0,0 +1,105 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { expectError, getSetup, loadFixture, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+
+describe("migrateConfig", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let payer: Keypair;
+
+  let configPda: PublicKey;
+
+  beforeEach(async () => {
+    payer = Keypair.generate();
+
+    ({ client } = await getSetup([
+      {
+        publicKey: payer.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    // config written by the initial release, before versioning
+    configPda = await loadFixture("config_v0");
+  });
+
+  test("migrate config from unversioned layout", async () => {
+    await program.methods
+      .migrateConfig()
+      .accounts({
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.version).toBe(1);
+    expect(
+      configAcc.authority.equals(
+        new PublicKey("9PVDX6prPftPEFjeaoViPfzUW9kBwDNbaTmoKyYtqwPt"),
+      ),
+    ).toBeTrue();
+    // fields added after the initial release keep the defaults of initializeConfig
+    expect(
+      configAcc.complianceAuthority.equals(configAcc.authority),
+    ).toBeTrue();
+    expect(configAcc.minLoanToValueBps).toBe(15000);
+    expect(configAcc.liquidationThresholdBps).toBe(12500);
+    expect(configAcc.liquidationBonusBps).toBe(250);
+    expect(configAcc.auctionDuration).toBe(0);
+    expect(configAcc.redemptionBaseRateBps).toBe(0);
+    expect(configAcc.lastRedemptionAt.eq(new BN(0))).toBeTrue();
+    expect(configAcc.maxFlashMint.eq(new BN(0))).toBeTrue();
+    expect(configAcc.swapAdapter.equals(PublicKey.default)).toBeTrue();
+    expect(configAcc.deleverageSlippageBps).toBe(0);
+    expect(configAcc.totalDebt.eq(new BN(0))).toBeTrue();
+    expect(configAcc.bump).toBe(254);
+    expect(configAcc.mintBump).toBe(255);
+
+    const configInfo = await connection.getAccountInfo(configPda);
+
+    expect(configInfo.data.length).toBe(program.account.config.size);
+    expect(configInfo.lamports).toBeGreaterThanOrEqual(
+      await connection.getMinimumBalanceForRentExemption(
+        program.account.config.size,
+      ),
+    );
+  });
+
+  test("throws if config is already migrated", async () => {
+    await program.methods
+      .migrateConfig()
+      .accounts({
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+
+    try {
+      await program.methods
+        .migrateConfig()
+        .accounts({
+          payer: payer.publicKey,
+        })
+        .signers([payer])
+        .rpc();
+    } catch (err) {
+      expectError(err, "AccountAlreadyMigrated");
+      return;
+    }
+
+    throw new Error("Config should not be migrated twice");
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda]);
+  });
+});
@@ -0,0 +1,111 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { expectError, getSetup, loadFixture, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { MINT_DECIMALS } from "../constants";
+
+describe("migratePosition", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let payer: Keypair;
+
+  let configPda: PublicKey;
+  let positionPda: PublicKey;
+
+  const depositor = new PublicKey(
+    "7UcfsqnsB8VyS54wQEru37GCpHmhJXcR8VKvQFfCTdnu",
+  );
+  const amountMinted = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+  beforeEach(async () => {
+    payer = Keypair.generate();
+
+    ({ client } = await getSetup([
+      {
+        publicKey: payer.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    // config and position in layout before versioning
+    configPda = await loadFixture("config_v0");
+    positionPda = await loadFixture("position_v0");
+
+    // config must be migrated before positions
+    await program.methods
+      .migrateConfig()
+      .accounts({
+        payer: payer.publicKey,
+      })
+      .signers([payer])
+      .rpc();
+  });
+
+  test("migrate position from unversioned layout", async () => {
+    await program.methods
+      .migratePosition()
+      .accounts({
+        payer: payer.publicKey,
+        depositor,
+      })
+      .signers([payer])
+      .rpc();
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(positionAcc.version).toBe(1);
+    expect(positionAcc.depositor.equals(depositor)).toBeTrue();
+    expect(positionAcc.amountMinted.eq(new BN(amountMinted))).toBeTrue();
+    expect(positionAcc.auctionEndsAt.eqn(0)).toBeTrue();
+    expect(positionAcc.bump).toBe(254);
+    expect(positionAcc.vaultBump).toBe(255);
+
+    const positionInfo = await connection.getAccountInfo(positionPda);
+
+    expect(positionInfo.data.length).toBe(program.account.position.size);
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.totalDebt.eq(new BN(amountMinted))).toBeTrue();
+  });
+
+  test("throws if position is already migrated", async () => {
+    await program.methods
+      .migratePosition()
+      .accounts({
+        payer: payer.publicKey,
+        depositor,
+      })
+      .signers([payer])
+      .rpc();
+
+    try {
+      await program.methods
+        .migratePosition()
+        .accounts({
+          payer: payer.publicKey,
+          depositor,
+        })
+        .signers([payer])
+        .rpc();
+    } catch (err) {
+      expectError(err, "AccountAlreadyMigrated");
+      return;
+    }
+
+    throw new Error("Position should not be migrated twice");
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, positionPda]);
+  });
+});
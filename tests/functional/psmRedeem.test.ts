@@ -0,0 +1,157 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool } from "../surfpool";
+
+describe("psmRedeem", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let user: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let stableMint: PublicKey;
+  let psmPda: PublicKey;
+  let reserveAta: PublicKey;
+
+  const mintFeeBps = 10; // 0.1%
+  const redeemFeeBps = 20; // 0.2%
+  const debtCeiling = 1_000_000 * Math.pow(10, MINT_DECIMALS); // $1,000,000
+  const amount = 100 * Math.pow(10, MINT_DECIMALS); // $100
+
+  beforeEach(async () => {
+    [configAuthority, user] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: user.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    stableMint = await Surfpool.initMint({});
+    psmPda = StablecoinClient.getPsmPda(stableMint);
+    reserveAta = getAssociatedTokenAddressSync(stableMint, psmPda, true);
+
+    await program.methods
+      .initializePsm({
+        mintFeeBps,
+        redeemFeeBps,
+        debtCeiling: new BN(debtCeiling),
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        stableMint,
+        stableTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop user stable tokens to swap
+    await Surfpool.setTokenAccount({
+      mint: stableMint.toBase58(),
+      owner: user.publicKey.toBase58(),
+      update: {
+        amount,
+      },
+    });
+
+    await program.methods
+      .psmMint(new BN(amount))
+      .accounts({
+        user: user.publicKey,
+        stableMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        stableTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+  });
+
+  test("swap stablecoin into stable token", async () => {
+    const amountToRedeem = 50 * Math.pow(10, MINT_DECIMALS); // $50
+
+    await program.methods
+      .psmRedeem(new BN(amountToRedeem))
+      .accounts({
+        user: user.publicKey,
+        stableMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        stableTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    const amountMinted = amount - (amount * mintFeeBps) / 10000;
+    const amountOut = amountToRedeem - (amountToRedeem * redeemFeeBps) / 10000;
+
+    const userAta = getAssociatedTokenAddressSync(
+      mintPda,
+      user.publicKey,
+      !PublicKey.isOnCurve(user.publicKey),
+    );
+
+    const userAtaAcc = await getAccount(connection, userAta);
+
+    expect(userAtaAcc.amount).toBe(BigInt(amountMinted - amountToRedeem));
+
+    const userStableAta = getAssociatedTokenAddressSync(
+      stableMint,
+      user.publicKey,
+      !PublicKey.isOnCurve(user.publicKey),
+    );
+
+    const userStableAtaAcc = await getAccount(connection, userStableAta);
+
+    expect(userStableAtaAcc.amount).toBe(BigInt(amountOut));
+
+    const reserveAtaAcc = await getAccount(connection, reserveAta);
+
+    expect(reserveAtaAcc.amount).toBe(BigInt(amount - amountOut));
+
+    const psmAcc = await client.fetchProgramAccount(psmPda, "psm");
+
+    expect(psmAcc.debt.eq(new BN(amountMinted - amountToRedeem))).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda]);
+  });
+});
@@ -0,0 +1,180 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  MINT_DECIMALS,
+  SOL_USD_FEED_ID,
+  SOL_USD_ORACLE_QUOTE,
+} from "../constants";
+import {
+  getAssociatedTokenAddressSync,
+  Queue,
+} from "@switchboard-xyz/on-demand";
+import { CrossbarClient } from "@switchboard-xyz/common";
+import { Surfpool } from "../surfpool";
+
+describe("redeem", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+  let crossbarClient: CrossbarClient;
+  let queue: Queue;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+  let redeemer: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let positionPda: PublicKey;
+  let vaultPda: PublicKey;
+
+  const oracleQuote = SOL_USD_ORACLE_QUOTE;
+  const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+  const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+  beforeEach(async () => {
+    [configAuthority, depositor, redeemer] = Array.from({ length: 3 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client, crossbarClient, queue } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: redeemer.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    // deposit SOL as collateral
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([ed25519Ix])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    vaultPda = StablecoinClient.getVaultPda(positionPda);
+  });
+
+  test("redeem stablecoin for SOL collateral", async () => {
+    const amountToRedeem = 100 * Math.pow(10, MINT_DECIMALS); // $100
+
+    // airdrop redeemer mint tokens to redeem
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: redeemer.publicKey.toBase58(),
+      update: {
+        amount: amountToRedeem,
+      },
+    });
+
+    const preRedeemerBal = await connection.getBalance(redeemer.publicKey);
+    const preVaultBal = await connection.getBalance(vaultPda);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    await program.methods
+      .redeem(new BN(amountToRedeem))
+      .preInstructions([ed25519Ix])
+      .accounts({
+        redeemer: redeemer.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .remainingAccounts([
+        { pubkey: positionPda, isSigner: false, isWritable: true },
+        { pubkey: vaultPda, isSigner: false, isWritable: true },
+      ])
+      .signers([redeemer])
+      .rpc();
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(
+      positionAcc.amountMinted.eq(new BN(amountToMint - amountToRedeem)),
+    ).toBeTrue();
+
+    const postRedeemerBal = await connection.getBalance(redeemer.publicKey);
+    const postVaultBal = await connection.getBalance(vaultPda);
+
+    expect(postRedeemerBal).toBeGreaterThan(preRedeemerBal);
+    expect(preVaultBal).toBeGreaterThan(postVaultBal);
+
+    const redeemerAta = getAssociatedTokenAddressSync(
+      mintPda,
+      redeemer.publicKey,
+      !PublicKey.isOnCurve(redeemer.publicKey),
+    );
+
+    const redeemerAtaAcc = await getAccount(connection, redeemerAta);
+
+    expect(redeemerAtaAcc.amount).toBe(0n);
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    // redeeming 40% of supply raises base rate by 20%
+    expect(configAcc.redemptionBaseRateBps).toBe(2000);
+    expect(configAcc.lastRedemptionAt.gtn(0)).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda]);
+  });
+});
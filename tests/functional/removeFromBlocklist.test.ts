@@ -0,0 +1,85 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("removeFromBlocklist", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let blockedAddress: PublicKey;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let blocklistEntryPda: PublicKey;
+
+  beforeEach(async () => {
+    configAuthority = Keypair.generate();
+    blockedAddress = Keypair.generate().publicKey;
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    blocklistEntryPda = StablecoinClient.getBlocklistEntryPda(blockedAddress);
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .addToBlocklist(blockedAddress)
+      .accounts({
+        complianceAuthority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+  });
+
+  test("remove address from blocklist", async () => {
+    await program.methods
+      .removeFromBlocklist()
+      .accounts({
+        complianceAuthority: configAuthority.publicKey,
+        blocklistEntry: blocklistEntryPda,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const blocklistEntryInfo = await connection.getAccountInfo(
+      blocklistEntryPda,
+    );
+
+    expect(blocklistEntryInfo).toBeNull();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, blocklistEntryPda]);
+  });
+});
@@ -0,0 +1,270 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  STAKE_CONFIG_ID,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import { expectError, getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  MINT_DECIMALS,
+  SOL_USD_FEED_ID,
+  SOL_USD_ORACLE_QUOTE,
+} from "../constants";
+import { Queue } from "@switchboard-xyz/on-demand";
+import { CrossbarClient } from "@switchboard-xyz/common";
+import { Surfpool, TimeTravelConfig } from "../surfpool";
+
+describe("stakeCollateral", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+  let crossbarClient: CrossbarClient;
+  let queue: Queue;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+  let keeper: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let positionPda: PublicKey;
+  let vaultPda: PublicKey;
+  let stakePda: PublicKey;
+  let voteAccount: PublicKey;
+
+  const oracleQuote = SOL_USD_ORACLE_QUOTE;
+  const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+  const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+  const amountToStake = 3 * LAMPORTS_PER_SOL; // 3 SOL
+
+  async function stakeCollateral() {
+    await program.methods
+      .stakeCollateral(new BN(amountToStake))
+      .accounts({
+        depositor: depositor.publicKey,
+        voteAccount,
+        stakeConfig: STAKE_CONFIG_ID,
+      })
+      .signers([depositor])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    [configAuthority, depositor, keeper] = Array.from({ length: 3 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client, crossbarClient, queue } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: keeper.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+    vaultPda = StablecoinClient.getVaultPda(positionPda);
+    stakePda = StablecoinClient.getStakePda(positionPda);
+
+    // any validator on the forked cluster
+    const { current } = await connection.getVoteAccounts();
+    voteAccount = new PublicKey(current[0].votePubkey);
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // deposit SOL as collateral
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+  });
+
+  test("stake collateral", async () => {
+    const preVaultBal = await connection.getBalance(vaultPda);
+
+    await stakeCollateral();
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(positionAcc.stakedCollateral.eq(new BN(amountToStake))).toBeTrue();
+
+    const postVaultBal = await connection.getBalance(vaultPda);
+
+    expect(preVaultBal).toBe(postVaultBal + amountToStake);
+
+    const stakeAcc = await connection.getAccountInfo(stakePda);
+
+    expect(stakeAcc.owner).toStrictEqual(StakeProgram.programId);
+    expect(stakeAcc.lamports).toBe(amountToStake);
+
+    // staked lamports still count as collateral
+    const positionHealth = await program.methods
+      .getPositionHealth()
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accounts({
+        position: positionPda,
+        oracleQuote,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .view();
+
+    expect(positionHealth.collateral.eq(new BN(lamports))).toBeTrue();
+  });
+
+  test("throws if withdrawing staked collateral", async () => {
+    await stakeCollateral();
+
+    try {
+      await program.methods
+        .withdrawCollateral(
+          new BN(lamports - amountToStake + 1),
+          new BN(0),
+          null,
+          null,
+        )
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accountsPartial({
+          depositor: depositor.publicKey,
+          oracleQuote,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([depositor])
+        .rpc();
+    } catch (err) {
+      expectError(err, "MathOverflow");
+      return;
+    }
+
+    throw new Error("Withdrawal should exceed vault balance");
+  });
+
+  test("throws if keeper unstakes healthy position", async () => {
+    await stakeCollateral();
+
+    try {
+      await program.methods
+        .unstakeCollateral()
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accounts({
+          authority: keeper.publicKey,
+          depositor: depositor.publicKey,
+          oracleQuote,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([keeper])
+        .rpc();
+    } catch (err) {
+      expectError(err, "AboveLiquidationThreshold");
+      return;
+    }
+
+    throw new Error("Unstaking should fail for healthy position");
+  });
+
+  test("unstake and withdraw stake to vault", async () => {
+    await stakeCollateral();
+
+    await program.methods
+      .unstakeCollateral()
+      .preInstructions([
+        await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+      ])
+      .accounts({
+        authority: depositor.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    // wait out the cooldown
+    const { epoch } = await connection.getEpochInfo();
+    await Surfpool.timeTravel({
+      config: TimeTravelConfig.Epoch,
+      value: epoch + 2,
+    });
+
+    const preVaultBal = await connection.getBalance(vaultPda);
+    const stakeBal = await connection.getBalance(stakePda);
+
+    await program.methods
+      .withdrawStake()
+      .accounts({
+        depositor: depositor.publicKey,
+      })
+      .rpc();
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(positionAcc.stakedCollateral.eqn(0)).toBeTrue();
+
+    const postVaultBal = await connection.getBalance(vaultPda);
+
+    expect(postVaultBal).toBe(preVaultBal + stakeBal);
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.totalCollateral.eq(new BN(postVaultBal))).toBeTrue();
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, positionPda, stakePda]);
+  });
+});
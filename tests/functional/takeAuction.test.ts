@@ -0,0 +1,221 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  MINT_DECIMALS,
+  SOL_USD_FEED_ID,
+  SOL_USD_ORACLE_QUOTE,
+} from "../constants";
+import {
+  getAssociatedTokenAddressSync,
+  Queue,
+} from "@switchboard-xyz/on-demand";
+import { CrossbarClient } from "@switchboard-xyz/common";
+import { Surfpool } from "../surfpool";
+
+describe("takeAuction", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+  let crossbarClient: CrossbarClient;
+  let queue: Queue;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+  let keeper: Keypair;
+  let bidder: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let positionPda: PublicKey;
+  let vaultPda: PublicKey;
+  let auctionPda: PublicKey;
+
+  const oracleQuote = SOL_USD_ORACLE_QUOTE;
+  const lamports = 5 * LAMPORTS_PER_SOL; // 5 SOL
+  const amountToMint = 250 * Math.pow(10, MINT_DECIMALS); // $250
+
+  beforeEach(async () => {
+    [configAuthority, depositor, keeper, bidder] = Array.from(
+      { length: 4 },
+      () => Keypair.generate(),
+    );
+
+    ({ client, crossbarClient, queue } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: keeper.publicKey,
+      },
+      {
+        publicKey: bidder.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+
+    const ed25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    // deposit SOL as collateral
+    await program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .preInstructions([ed25519Ix])
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([depositor])
+      .rpc();
+
+    vaultPda = StablecoinClient.getVaultPda(positionPda);
+    auctionPda = StablecoinClient.getAuctionPda(positionPda);
+
+    // update config to meet liquidation requirements and enable auctions
+    const liquidationThresholdBps = 30000; // 300%
+    const minLoanToValueBps = 35000; // 350%
+    const auctionDuration = 600; // 10 minutes
+    const auctionPremiumBps = 1000; // 10%
+    const auctionDiscountBps = 2000; // 20%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+        auctionDuration,
+        auctionPremiumBps,
+        auctionDiscountBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const startAuctionEd25519Ix = await queue.fetchQuoteIx(crossbarClient, [
+      SOL_USD_FEED_ID,
+    ]);
+
+    await program.methods
+      .startAuction()
+      .preInstructions([startAuctionEd25519Ix])
+      .accountsPartial({
+        keeper: keeper.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: vaultPda,
+      })
+      .signers([keeper])
+      .rpc();
+  });
+
+  test("take entire auction of undercollaterized position", async () => {
+    const amountToBurn = amountToMint;
+
+    // airdrop bidder mint tokens to burn
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: bidder.publicKey.toBase58(),
+      update: {
+        amount: amountToBurn,
+      },
+    });
+
+    const preBidderBal = await connection.getBalance(bidder.publicKey);
+    const preDepositorBal = await connection.getBalance(depositor.publicKey);
+
+    await program.methods
+      .takeAuction(new BN(amountToBurn))
+      .accountsPartial({
+        bidder: bidder.publicKey,
+        depositor: depositor.publicKey,
+        keeper: keeper.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        position: positionPda,
+        auction: auctionPda,
+        vault: vaultPda,
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
+      })
+      .signers([bidder])
+      .rpc();
+
+    const postBidderBal = await connection.getBalance(bidder.publicKey);
+    const postDepositorBal = await connection.getBalance(depositor.publicKey);
+
+    expect(postBidderBal).toBeGreaterThan(preBidderBal);
+    expect(postDepositorBal).toBeGreaterThan(preDepositorBal);
+
+    const bidderAta = getAssociatedTokenAddressSync(
+      mintPda,
+      bidder.publicKey,
+      !PublicKey.isOnCurve(bidder.publicKey),
+    );
+
+    const bidderAtaAcc = await getAccount(connection, bidderAta);
+
+    expect(bidderAtaAcc.amount).toBe(0n);
+
+    const auctionAcc = await client.fetchProgramAccount(auctionPda, "auction");
+
+    expect(auctionAcc).toBeNull();
+
+    const positionAcc = await client.fetchProgramAccount(
+      positionPda,
+      "position",
+    );
+
+    expect(positionAcc).toBeNull();
+    expect(await connection.getBalance(vaultPda)).toBe(0);
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda]);
+  });
+});
@@ -1,7 +1,7 @@
 import { afterEach, beforeEach, describe, expect, test } from "bun:test";
 import { StablecoinClient } from "../StablecoinClient";
 import { Connection, Keypair, PublicKey } from "@solana/web3.js";
-import { getSetup, resetAccounts } from "../setup";
+import { expectError, getSetup, resetAccounts } from "../setup";
 import { Program } from "@coral-xyz/anchor";
 import { Stablecoin } from "../../target/types/stablecoin";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -75,6 +75,32 @@ describe("updateConfig", () => {
     expect(configAcc.minLoanToValueBps).toBe(minLoanToValueBps);
   });
 
+  test("throws if swap adapter is the stablecoin program", async () => {
+    try {
+      await program.methods
+        .updateConfig({
+          liquidationBonusBps: null,
+          liquidationThresholdBps: null,
+          minLoanToValueBps: null,
+          swapAdapter: program.programId,
+        })
+        .accounts({
+          authority: configAuthority.publicKey,
+        })
+        .signers([configAuthority])
+        .rpc();
+    } catch (err) {
+      expectError(err, "InvalidSwapAdapterProgram");
+
+      const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+      expect(configAcc.swapAdapter.equals(PublicKey.default)).toBeTrue();
+      return;
+    }
+
+    throw new Error("Update config should not accept stablecoin program");
+  });
+
   afterEach(async () => {
     await resetAccounts([configPda, mintPda]);
   });
@@ -7,7 +7,7 @@ import {
   PublicKey,
   SYSVAR_CLOCK_PUBKEY,
 } from "@solana/web3.js";
-import { getSetup, resetAccounts } from "../setup";
+import { expectError, getSetup, resetAccounts } from "../setup";
 import { BN, Program } from "@coral-xyz/anchor";
 import { Stablecoin } from "../../target/types/stablecoin";
 import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -89,7 +89,7 @@ describe("withdrawCollateral", () => {
 
     // deposit SOL as collateral
     await program.methods
-      .depositCollateral(new BN(lamports), new BN(amountToMint))
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
       .preInstructions([ed25519Ix])
       .accounts({
         depositor: depositor.publicKey,
@@ -125,7 +125,7 @@ describe("withdrawCollateral", () => {
     const amountToBurn = 125 * Math.pow(10, MINT_DECIMALS); // $125
 
     await program.methods
-      .withdrawCollateral(new BN(lamports), new BN(amountToBurn))
+      .withdrawCollateral(new BN(lamports), new BN(amountToBurn), null, null)
       .preInstructions([ed25519Ix])
       .accountsPartial({
         depositor: depositor.publicKey,
@@ -156,6 +156,50 @@ describe("withdrawCollateral", () => {
     const postVaultBal = await connection.getBalance(vaultPda);
 
     expect(preVaultBal).toBe(postVaultBal + lamports);
+
+    const configAcc = await client.fetchProgramAccount(configPda, "config");
+
+    expect(configAcc.totalCollateral.eq(new BN(postVaultBal))).toBeTrue();
+    expect(configAcc.totalDebt.eq(postPositionAcc.amountMinted)).toBeTrue();
+  });
+
+  test("throws if withdrawal lowers system collateral ratio below critical ratio", async () => {
+    // critical ratio above system collateral ratio enters recovery mode
+    const criticalCollateralRatioBps = 65000; // 650%
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        criticalCollateralRatioBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    try {
+      await program.methods
+        .withdrawCollateral(new BN(LAMPORTS_PER_SOL), new BN(0), null, null)
+        .preInstructions([
+          await queue.fetchQuoteIx(crossbarClient, [SOL_USD_FEED_ID]),
+        ])
+        .accountsPartial({
+          depositor: depositor.publicKey,
+          oracleQuote,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([depositor])
+        .rpc();
+    } catch (err) {
+      expectError(err, "SystemCollateralRatioBelowCritical");
+      return;
+    }
+
+    throw new Error("Withdrawal should fail in recovery mode");
   });
 
   afterEach(async () => {
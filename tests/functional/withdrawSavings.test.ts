@@ -0,0 +1,172 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool } from "../surfpool";
+
+describe("withdrawSavings", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let savingsPda: PublicKey;
+  let savingsDepositPda: PublicKey;
+  let savingsAta: PublicKey;
+  let depositorAta: PublicKey;
+
+  const amount = 100 * Math.pow(10, MINT_DECIMALS); // $100
+
+  beforeEach(async () => {
+    [configAuthority, depositor] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    savingsPda = StablecoinClient.getSavingsPda();
+    savingsDepositPda = StablecoinClient.getSavingsDepositPda(
+      depositor.publicKey,
+    );
+    savingsAta = getAssociatedTokenAddressSync(mintPda, savingsPda, true);
+    depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      !PublicKey.isOnCurve(depositor.publicKey),
+    );
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeSavings()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop depositor mint tokens to deposit
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: depositor.publicKey.toBase58(),
+      update: {
+        amount,
+      },
+    });
+  
+    await program.methods
+      .depositSavings(new BN(amount))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+  });
+
+  test("withdraw from savings", async () => {
+    const amountToWithdraw = amount / 2;
+
+    await program.methods
+      .withdrawSavings(new BN(amountToWithdraw))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const savingsAcc = await client.fetchProgramAccount(savingsPda, "savings");
+
+    expect(
+      savingsAcc.totalShares.eq(new BN(amount - amountToWithdraw)),
+    ).toBeTrue();
+
+    const savingsDepositAcc = await client.fetchProgramAccount(
+      savingsDepositPda,
+      "savingsDeposit",
+    );
+
+    expect(
+      savingsDepositAcc.shares.eq(new BN(amount - amountToWithdraw)),
+    ).toBeTrue();
+
+    const savingsAtaAcc = await getAccount(connection, savingsAta);
+
+    expect(savingsAtaAcc.amount).toBe(BigInt(amount - amountToWithdraw));
+
+    const depositorAtaAcc = await getAccount(connection, depositorAta);
+
+    expect(depositorAtaAcc.amount).toBe(BigInt(amountToWithdraw));
+  });
+
+  test("withdraw is capped at deposit value", async () => {
+    await program.methods
+      .withdrawSavings(new BN(2 * amount))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const savingsDepositAcc = await client.fetchProgramAccount(
+      savingsDepositPda,
+      "savingsDeposit",
+    );
+
+    expect(savingsDepositAcc.shares.eqn(0)).toBeTrue();
+
+    const depositorAtaAcc = await getAccount(connection, depositorAta);
+
+    expect(depositorAtaAcc.amount).toBe(BigInt(amount));
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, savingsPda, savingsAta]);
+  });
+});
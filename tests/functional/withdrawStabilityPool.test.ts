@@ -0,0 +1,153 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool } from "../surfpool";
+
+describe("withdrawStabilityPool", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let depositor: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let stabilityPoolPda: PublicKey;
+  let stabilityDepositPda: PublicKey;
+  let poolAta: PublicKey;
+  let depositorAta: PublicKey;
+
+  const amount = 100 * Math.pow(10, MINT_DECIMALS); // $100
+
+  beforeEach(async () => {
+    [configAuthority, depositor] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+      },
+      {
+        publicKey: depositor.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    stabilityPoolPda = StablecoinClient.getStabilityPoolPda();
+    stabilityDepositPda = StablecoinClient.getStabilityDepositPda(
+      depositor.publicKey,
+    );
+    poolAta = getAssociatedTokenAddressSync(mintPda, stabilityPoolPda, true);
+    depositorAta = getAssociatedTokenAddressSync(
+      mintPda,
+      depositor.publicKey,
+      !PublicKey.isOnCurve(depositor.publicKey),
+    );
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeStabilityPool()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // airdrop depositor mint tokens to deposit
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: depositor.publicKey.toBase58(),
+      update: {
+        amount,
+      },
+    });
+
+    await program.methods
+      .depositStabilityPool(new BN(amount))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+  });
+
+  test("withdraw from stability pool", async () => {
+    const amountToWithdraw = amount / 2;
+
+    await program.methods
+      .withdrawStabilityPool(new BN(amountToWithdraw))
+      .accounts({
+        depositor: depositor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor])
+      .rpc();
+
+    const stabilityPoolAcc = await client.fetchProgramAccount(
+      stabilityPoolPda,
+      "stabilityPool",
+    );
+
+    expect(
+      stabilityPoolAcc.totalDeposits.eq(new BN(amount - amountToWithdraw)),
+    ).toBeTrue();
+
+    const stabilityDepositAcc = await client.fetchProgramAccount(
+      stabilityDepositPda,
+      "stabilityDeposit",
+    );
+
+    expect(
+      stabilityDepositAcc.amount.eq(new BN(amount - amountToWithdraw)),
+    ).toBeTrue();
+
+    const poolAtaAcc = await getAccount(connection, poolAta);
+
+    expect(poolAtaAcc.amount).toBe(BigInt(amount - amountToWithdraw));
+
+    const depositorAtaAcc = await getAccount(connection, depositorAta);
+
+    expect(depositorAtaAcc.amount).toBe(BigInt(amountToWithdraw));
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, stabilityPoolPda, poolAta]);
+  });
+});
@@ -0,0 +1,215 @@
+import { afterEach, beforeEach, describe, expect, test } from "bun:test";
+import { StablecoinClient } from "../StablecoinClient";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expectError, getSetup, resetAccounts } from "../setup";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { MINT_DECIMALS } from "../constants";
+import { getAssociatedTokenAddressSync } from "@switchboard-xyz/on-demand";
+import { Surfpool } from "../surfpool";
+
+describe("withdrawTreasury", () => {
+  let client: StablecoinClient;
+  let program: Program<Stablecoin>;
+  let connection: Connection;
+
+  let configAuthority: Keypair;
+  let recipient: Keypair;
+
+  let configPda: PublicKey;
+  let mintPda: PublicKey;
+  let treasuryPda: PublicKey;
+  let treasuryAta: PublicKey;
+  let recipientAta: PublicKey;
+
+  const treasuryLamports = 2 * LAMPORTS_PER_SOL; // 2 SOL
+  const treasuryAmount = 100 * Math.pow(10, MINT_DECIMALS); // $100
+  const solWithdrawalLimit = LAMPORTS_PER_SOL; // 1 SOL
+  const tokenWithdrawalLimit = 50 * Math.pow(10, MINT_DECIMALS); // $50
+
+  beforeEach(async () => {
+    [configAuthority, recipient] = Array.from({ length: 2 }, () =>
+      Keypair.generate(),
+    );
+
+    ({ client } = await getSetup([
+      {
+        publicKey: configAuthority.publicKey,
+        lamports: 10 * LAMPORTS_PER_SOL,
+      },
+      {
+        publicKey: recipient.publicKey,
+      },
+    ]));
+
+    program = client.program;
+    connection = client.connection;
+
+    configPda = StablecoinClient.getConfigPda();
+    mintPda = StablecoinClient.getMintPda();
+    treasuryPda = StablecoinClient.getTreasuryPda();
+    treasuryAta = getAssociatedTokenAddressSync(mintPda, treasuryPda, true);
+    recipientAta = getAssociatedTokenAddressSync(
+      mintPda,
+      recipient.publicKey,
+      !PublicKey.isOnCurve(recipient.publicKey),
+    );
+
+    // initialize config
+    const liquidationBonusBps = 250; // 2.5%
+    const liquidationThresholdBps = 12500; // 125%
+    const minLoanToValueBps = 15000; // 150%
+
+    await program.methods
+      .initializeConfig({
+        liquidationBonusBps,
+        liquidationThresholdBps,
+        minLoanToValueBps,
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    await program.methods
+      .updateConfig({
+        liquidationBonusBps: null,
+        liquidationThresholdBps: null,
+        minLoanToValueBps: null,
+        treasurySolWithdrawalLimit: new BN(solWithdrawalLimit),
+        treasuryTokenWithdrawalLimit: new BN(tokenWithdrawalLimit),
+      })
+      .accounts({
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    // fund treasury with SOL and stablecoin
+    await program.methods
+      .initializeTreasury()
+      .accounts({
+        authority: configAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .postInstructions([
+        SystemProgram.transfer({
+          fromPubkey: configAuthority.publicKey,
+          toPubkey: treasuryPda,
+          lamports: treasuryLamports,
+        }),
+      ])
+      .signers([configAuthority])
+      .rpc();
+
+    await Surfpool.setTokenAccount({
+      mint: mintPda.toBase58(),
+      owner: treasuryPda.toBase58(),
+      update: {
+        amount: treasuryAmount,
+      },
+    });
+  });
+
+  test("withdraw SOL and stablecoin from treasury", async () => {
+    const preRecipientBal = await connection.getBalance(recipient.publicKey);
+
+    await program.methods
+      .withdrawTreasury(
+        new BN(solWithdrawalLimit),
+        new BN(tokenWithdrawalLimit),
+      )
+      .accounts({
+        authority: configAuthority.publicKey,
+        recipient: recipient.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const postRecipientBal = await connection.getBalance(recipient.publicKey);
+
+    expect(postRecipientBal).toBe(preRecipientBal + solWithdrawalLimit);
+
+    const recipientAtaAcc = await getAccount(connection, recipientAta);
+
+    expect(recipientAtaAcc.amount).toBe(BigInt(tokenWithdrawalLimit));
+
+    const treasuryAtaAcc = await getAccount(connection, treasuryAta);
+
+    expect(treasuryAtaAcc.amount).toBe(
+      BigInt(treasuryAmount - tokenWithdrawalLimit),
+    );
+
+    const treasuryAcc = await client.fetchProgramAccount(
+      treasuryPda,
+      "treasury",
+    );
+
+    expect(treasuryAcc.solWithdrawn.eq(new BN(solWithdrawalLimit))).toBeTrue();
+    expect(
+      treasuryAcc.tokenWithdrawn.eq(new BN(tokenWithdrawalLimit)),
+    ).toBeTrue();
+  });
+
+  test("throws if epoch limit is exceeded", async () => {
+    await program.methods
+      .withdrawTreasury(new BN(solWithdrawalLimit), new BN(0))
+      .accounts({
+        authority: configAuthority.publicKey,
+        recipient: recipient.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    try {
+      await program.methods
+        .withdrawTreasury(new BN(1), new BN(0))
+        .accounts({
+          authority: configAuthority.publicKey,
+          recipient: recipient.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([configAuthority])
+        .rpc();
+    } catch (err) {
+      expectError(err, "TreasuryWithdrawalLimitExceeded");
+      return;
+    }
+
+    throw new Error("Withdrawal above epoch limit should not succeed");
+  });
+
+  test("throws if signer is not config authority", async () => {
+    try {
+      await program.methods
+        .withdrawTreasury(new BN(solWithdrawalLimit), new BN(0))
+        .accounts({
+          authority: recipient.publicKey,
+          recipient: recipient.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([recipient])
+        .rpc();
+    } catch (err) {
+      expectError(err, "ConstraintHasOne");
+      return;
+    }
+
+    throw new Error("Withdrawal by non-authority should not succeed");
+  });
+
+  afterEach(async () => {
+    await resetAccounts([configPda, mintPda, treasuryPda, treasuryAta]);
+  });
+});
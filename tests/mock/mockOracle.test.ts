@@ -0,0 +1,327 @@
+import { beforeEach, describe, expect, test } from "bun:test";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Signer,
+  SYSVAR_CLOCK_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { FailedTransactionMetadata, LiteSVM } from "litesvm";
+import { LiteSVMProvider } from "anchor-litesvm";
+import { join } from "path";
+import idl from "../../target/idl/stablecoin.json";
+import mockOracleIdl from "../../target/idl/mock_oracle.json";
+import { Stablecoin } from "../../target/types/stablecoin";
+import { MockOracle } from "../../target/types/mock_oracle";
+import { StablecoinClient } from "../StablecoinClient";
+import { MINT_DECIMALS, STABLECOIN_PROGRAM_ID } from "../constants";
+import {
+  getPriceFeedPda,
+  MOCK_ORACLE_PROGRAM_ID,
+  setPriceIx,
+  SOL_USD_FEED_ID_BYTES,
+} from "../mockOracle";
+
+// requires `bun run build:mock`
+const DEPLOY_DIR = join(import.meta.dir, "../../target/deploy");
+
+describe("mock oracle", () => {
+  let svm: LiteSVM;
+  let program: Program<Stablecoin>;
+  let oracle: Program<MockOracle>;
+
+  let oracleAuthority: Keypair;
+  let depositor: Keypair;
+  let liquidator: Keypair;
+
+  let positionPda: PublicKey;
+
+  const lamports = LAMPORTS_PER_SOL; // 1 SOL
+  const amountToMint = 60 * Math.pow(10, MINT_DECIMALS); // $60
+
+  function send(ixs: TransactionInstruction[], signers: Signer[]) {
+    const tx = new Transaction().add(...ixs);
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = signers[0].publicKey;
+    tx.sign(...signers);
+
+    const result = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+
+    return result;
+  }
+
+  function expectSuccess(result: ReturnType<LiteSVM["sendTransaction"]>) {
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(result.meta().logs().join("\n"));
+    }
+  }
+
+  function expectError(
+    result: ReturnType<LiteSVM["sendTransaction"]>,
+    code: string,
+  ) {
+    expect(result).toBeInstanceOf(FailedTransactionMetadata);
+    expect(
+      (result as FailedTransactionMetadata).meta().logs().join("\n"),
+    ).toContain(`Error Code: ${code}`);
+  }
+
+  function createFundedKeypair(lamports = 10 * LAMPORTS_PER_SOL) {
+    const keypair = Keypair.generate();
+    svm.airdrop(keypair.publicKey, BigInt(lamports));
+
+    return keypair;
+  }
+
+  async function depositCollateralIx(
+    depositor: Keypair,
+    lamports: number,
+    amountToMint: number,
+  ) {
+    return program.methods
+      .depositCollateral(new BN(lamports), new BN(amountToMint), null, null)
+      .accounts({
+        depositor: depositor.publicKey,
+        oracleQuote: getPriceFeedPda(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .instruction();
+  }
+
+  async function liquidatePositionIx(amountToBurn: number) {
+    return program.methods
+      .liquidatePosition(new BN(amountToBurn), null, null, null)
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        depositor: depositor.publicKey,
+        oracleQuote: getPriceFeedPda(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        position: positionPda,
+        vault: StablecoinClient.getVaultPda(positionPda),
+        stakeAccount: null,
+        seizedStake: null,
+        stakeProgram: null,
+      })
+      .instruction();
+  }
+
+  beforeEach(async () => {
+    svm = new LiteSVM();
+    svm.addProgramFromFile(
+      STABLECOIN_PROGRAM_ID,
+      join(DEPLOY_DIR, "stablecoin.so"),
+    );
+    svm.addProgramFromFile(
+      MOCK_ORACLE_PROGRAM_ID,
+      join(DEPLOY_DIR, "mock_oracle.so"),
+    );
+
+    const provider = new LiteSVMProvider(svm);
+    program = new Program<Stablecoin>(idl as Stablecoin, provider);
+    oracle = new Program<MockOracle>(mockOracleIdl as MockOracle, provider);
+
+    const configAuthority = createFundedKeypair();
+    oracleAuthority = createFundedKeypair();
+    depositor = createFundedKeypair();
+    liquidator = createFundedKeypair();
+
+    positionPda = StablecoinClient.getPositionPda(depositor.publicKey);
+
+    expectSuccess(
+      send(
+        [
+          await program.methods
+            .initializeConfig({
+              liquidationBonusBps: 250, // 2.5%
+              liquidationThresholdBps: 12500, // 125%
+              minLoanToValueBps: 15000, // 150%
+            })
+            .accounts({
+              authority: configAuthority.publicKey,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .instruction(),
+          await program.methods
+            .initializeTreasury()
+            .accounts({
+              authority: configAuthority.publicKey,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .instruction(),
+        ],
+        [configAuthority],
+      ),
+    );
+
+    expectSuccess(
+      send(
+        [
+          await oracle.methods
+            .initializePriceFeed(SOL_USD_FEED_ID_BYTES)
+            .accounts({
+              authority: oracleAuthority.publicKey,
+            })
+            .instruction(),
+          await setPriceIx(oracle, oracleAuthority, 100),
+        ],
+        [oracleAuthority],
+      ),
+    );
+
+    expectSuccess(
+      send(
+        [await depositCollateralIx(depositor, lamports, amountToMint)],
+        [depositor],
+      ),
+    );
+
+    // liquidator mints $100 against 5 SOL to repay the position
+    expectSuccess(
+      send(
+        [
+          await depositCollateralIx(
+            liquidator,
+            5 * LAMPORTS_PER_SOL,
+            100 * Math.pow(10, MINT_DECIMALS),
+          ),
+        ],
+        [liquidator],
+      ),
+    );
+  });
+
+  test("only authority can set price", async () => {
+    const attacker = createFundedKeypair();
+
+    expectError(
+      send([await setPriceIx(oracle, attacker, 1)], [attacker]),
+      "ConstraintHasOne",
+    );
+  });
+
+  test("liquidate after price crash", async () => {
+    // 166% at $100
+    expectError(
+      send([await liquidatePositionIx(amountToMint)], [liquidator]),
+      "AboveLiquidationThreshold",
+    );
+
+    // 116% at $70
+    expectSuccess(
+      send([await setPriceIx(oracle, oracleAuthority, 70)], [oracleAuthority]),
+    );
+    expectSuccess(
+      send([await liquidatePositionIx(amountToMint)], [liquidator]),
+    );
+
+    expect(svm.getAccount(positionPda)).toBeNull();
+  });
+
+  test("mint up to minimum loan-to-value", async () => {
+    const borrower = createFundedKeypair();
+
+    // 1 SOL at $100 and 150% backs $66.6666666, rounded down to base units
+    expectError(
+      send(
+        [await depositCollateralIx(borrower, LAMPORTS_PER_SOL, 66_666_667)],
+        [borrower],
+      ),
+      "BelowMinimumHealthFactor",
+    );
+    expectSuccess(
+      send(
+        [await depositCollateralIx(borrower, LAMPORTS_PER_SOL, 66_666_666)],
+        [borrower],
+      ),
+    );
+  });
+
+  test("withdraw down to minimum loan-to-value", async () => {
+    const withdrawCollateralIx = async (lamports: number) =>
+      program.methods
+        .withdrawCollateral(new BN(lamports), new BN(0), null, null)
+        .accountsPartial({
+          depositor: depositor.publicKey,
+          oracleQuote: getPriceFeedPda(),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .instruction();
+
+    expectSuccess(
+      send([await setPriceIx(oracle, oracleAuthority, 99)], [oracleAuthority]),
+    );
+
+    // $60 at 150% and $99 requires 0.90909090909 SOL, rounded up to lamports
+    expectError(
+      send([await withdrawCollateralIx(90_909_091)], [depositor]),
+      "BelowMinimumHealthFactor",
+    );
+    expectSuccess(send([await withdrawCollateralIx(90_909_090)], [depositor]));
+
+    expect(svm.getBalance(StablecoinClient.getVaultPda(positionPda))).toBe(
+      909_090_910n,
+    );
+  });
+
+  test("throws if redeeming from underwater position", async () => {
+    // 83% at $50
+    expectSuccess(
+      send([await setPriceIx(oracle, oracleAuthority, 50)], [oracleAuthority]),
+    );
+
+    const redeemIx = await program.methods
+      .redeem(new BN(amountToMint))
+      .accounts({
+        redeemer: liquidator.publicKey,
+        oracleQuote: getPriceFeedPda(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .remainingAccounts([
+        { pubkey: positionPda, isSigner: false, isWritable: true },
+        {
+          pubkey: StablecoinClient.getVaultPda(positionPda),
+          isSigner: false,
+          isWritable: true,
+        },
+      ])
+      .instruction();
+
+    expectError(send([redeemIx], [liquidator]), "PositionUnderwater");
+  });
+
+  test("throws if price is stale", async () => {
+    svm.warpToSlot(1000n);
+
+    expectError(
+      send([await depositCollateralIx(depositor, lamports, 0)], [depositor]),
+      "StalePrice",
+    );
+
+    expectSuccess(
+      send([await setPriceIx(oracle, oracleAuthority, 100)], [oracleAuthority]),
+    );
+    expectSuccess(
+      send([await depositCollateralIx(depositor, lamports, 0)], [depositor]),
+    );
+  });
+
+  test("throws if price is not positive", async () => {
+    expectSuccess(
+      send([await setPriceIx(oracle, oracleAuthority, -1)], [oracleAuthority]),
+    );
+
+    expectError(
+      send([await depositCollateralIx(depositor, lamports, 0)], [depositor]),
+      "InvalidPrice",
+    );
+  });
+});
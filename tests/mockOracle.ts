@@ -0,0 +1,61 @@
+import { BN, Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import idl from "../target/idl/mock_oracle.json";
+import { MockOracle } from "../target/types/mock_oracle";
+import { SOL_USD_FEED_ID } from "./constants";
+
+export const MOCK_ORACLE_PROGRAM_ID = new PublicKey(idl.address);
+export const SOL_USD_FEED_ID_BYTES = Array.from(
+  Buffer.from(SOL_USD_FEED_ID.slice(2), "hex"),
+);
+
+/**
+ * Derives the mock price feed standing in for a Switchboard feed.
+ * @param feedId Switchboard feed id bytes
+ */
+export function getPriceFeedPda(feedId = SOL_USD_FEED_ID_BYTES) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("price_feed"), Buffer.from(feedId)],
+    MOCK_ORACLE_PROGRAM_ID,
+  )[0];
+}
+
+/**
+ * Converts a USD amount with up to 6 decimals to the 18-decimal fixed point
+ * stored by the mock oracle.
+ */
+export function toMockPrice(price: number) {
+  return new BN(Math.round(price * 1e6)).mul(new BN(10).pow(new BN(12)));
+}
+
+/**
+ * Builds a `set_price` instruction. Timestamp and slot default to the current
+ * clock on chain; pass an older slot to make the price stale.
+ */
+export async function setPriceIx(
+  program: Program<MockOracle>,
+  authority: Keypair,
+  price: number,
+  {
+    confidence = 0,
+    timestamp = null,
+    slot = null,
+  }: {
+    confidence?: number;
+    timestamp?: number | null;
+    slot?: number | null;
+  } = {},
+) {
+  return program.methods
+    .setPrice({
+      price: toMockPrice(price),
+      confidence: toMockPrice(confidence),
+      timestamp: timestamp === null ? null : new BN(timestamp),
+      slot: slot === null ? null : new BN(slot),
+    })
+    .accounts({
+      authority: authority.publicKey,
+      priceFeed: getPriceFeedPda(),
+    })
+    .instruction();
+}
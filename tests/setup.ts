@@ -93,6 +93,26 @@ export async function resetAccounts(pubkeys: PublicKey[]) {
     });
 }
 
+/**
+ * Loads an account dumped with `solana account --output json` from `tests/fixtures`.
+ * @param name fixture file name without extension
+ */
+export async function loadFixture(name: string) {
+  const { pubkey, account } = await Bun.file(
+    `${import.meta.dir}/fixtures/${name}.json`,
+  ).json();
+
+  await Surfpool.setAccount({
+    publicKey: pubkey,
+    data: Buffer.from(account.data[0], "base64").toHex(),
+    executable: account.executable,
+    lamports: account.lamports,
+    owner: account.owner,
+  });
+
+  return new PublicKey(pubkey);
+}
+
 export async function buildAndSendv0Tx(
   ixs: TransactionInstruction[],
   signers: Signer[],
@@ -0,0 +1,42 @@
+import { PublicKey } from "@solana/web3.js";
+import { LiteSVM } from "litesvm";
+import { readdirSync, readFileSync } from "fs";
+import { join } from "path";
+
+/**
+ * Reads an account dumped with `solana account --output json`.
+ */
+export function readAccountFixture(path: string) {
+  const { pubkey, account } = JSON.parse(readFileSync(path, "utf-8"));
+
+  return {
+    pubkey: new PublicKey(pubkey),
+    account: {
+      lamports: account.lamports,
+      data: Buffer.from(account.data[0], "base64"),
+      owner: new PublicKey(account.owner),
+      executable: account.executable,
+      rentEpoch: 0,
+    },
+  };
+}
+
+/**
+ * Loads every account of a snapshot exported by `stablecoin-snapshot export`
+ * into LiteSVM. The program itself must be added separately.
+ * @param dir snapshot directory
+ * @returns addresses of loaded accounts
+ */
+export function loadSnapshot(svm: LiteSVM, dir: string) {
+  const accountsDir = join(dir, "accounts");
+
+  return readdirSync(accountsDir)
+    .filter((file) => file.endsWith(".json"))
+    .map((file) => {
+      const { pubkey, account } = readAccountFixture(join(accountsDir, file));
+
+      svm.setAccount(pubkey, account);
+
+      return pubkey;
+    });
+}